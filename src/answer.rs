@@ -0,0 +1,120 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+use num::{BigInt, ToPrimitive};
+
+/// A puzzle answer. Integers that fit in an `i64` are always stored as
+/// `Integer` so that equal values compare equal regardless of source type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(BigInt),
+    Text(String),
+    List(Vec<Answer>),
+}
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        match value.to_i64() {
+            Some(v) => Answer::Integer(v),
+            None => Answer::BigInteger(value),
+        }
+    }
+}
+
+macro_rules! answer_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    match i64::try_from(value) {
+                        Ok(v) => Answer::Integer(v),
+                        Err(_) => Answer::BigInteger(BigInt::from(value)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+answer_from_integer!(i32, i64, i128, isize, u8, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Vec<T>> for Answer {
+    fn from(value: Vec<T>) -> Self {
+        Answer::List(value.into_iter().map(Into::into).collect())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(v) => write!(f, "{}", v),
+            Answer::BigInteger(v) => write!(f, "{}", v),
+            Answer::Text(v) => write!(f, "{}", v),
+            Answer::List(v) => write!(f, "{}", v.iter().join(",")),
+        }
+    }
+}
+
+/// Expected values accepted by the `sample!` macro. Integer literals of any
+/// size infer as `i128`, lists are written as arrays.
+pub trait Expected {
+    fn into_answer(self) -> Answer;
+}
+
+impl Expected for i128 {
+    fn into_answer(self) -> Answer {
+        self.into()
+    }
+}
+
+impl Expected for &str {
+    fn into_answer(self) -> Answer {
+        self.into()
+    }
+}
+
+impl<const N: usize> Expected for [i128; N] {
+    fn into_answer(self) -> Answer {
+        self.to_vec().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers_normalize() {
+        assert_eq!(Answer::from(5u64), Answer::from(5i32));
+        assert_eq!(Answer::Integer(5), Answer::from(BigInt::from(5)));
+        assert!(matches!(Answer::from(u64::MAX), Answer::BigInteger(_)));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("42", Answer::from(42usize).to_string());
+        assert_eq!("co,de", Answer::from("co,de").to_string());
+        assert_eq!("4,6,3", Answer::from(vec![4u64, 6, 3]).to_string());
+        assert_eq!("18446744073709551615", Answer::from(u64::MAX).to_string());
+    }
+
+    #[test]
+    fn test_expected() {
+        assert_eq!(Answer::Integer(11), Expected::into_answer(11));
+        assert_eq!(Answer::from(vec![6, 1]), Expected::into_answer([6, 1]));
+        assert_eq!(Answer::from("6,1"), Expected::into_answer("6,1"));
+    }
+}
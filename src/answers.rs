@@ -0,0 +1,123 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::answer::Answer;
+
+pub const ANSWERS_DIR: &str = "answers";
+
+/// Conventional answers location for a puzzle, e.g. `answers/day_01.txt`.
+pub fn default_answers_path(name: &str) -> PathBuf {
+    Path::new(ANSWERS_DIR).join(format!("{}.txt", name))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Correct,
+    Incorrect(String),
+    Unknown,
+}
+
+/// Recorded answers for a single day, stored as `part1: <answer>` lines.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    parts: BTreeMap<u8, String>,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Self {
+        let parts = input
+            .lines()
+            .filter_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                let part = key.trim().strip_prefix("part")?.parse().ok()?;
+                Some((part, value.trim().to_string()))
+            })
+            .collect();
+
+        Self { parts }
+    }
+
+    /// Load answers from disk. A missing file has no recorded answers.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Ok(Self::parse(&contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        self.parts.get(&part).map(|s| s.as_str())
+    }
+
+    pub fn set(&mut self, part: u8, answer: &Answer) {
+        self.parts.insert(part, answer.to_string());
+    }
+
+    /// Compare against the recorded answer using its textual form, which is
+    /// how answers are stored on disk.
+    pub fn check(&self, part: u8, answer: &Answer) -> Check {
+        match self.get(part) {
+            Some(expected) if *expected == answer.to_string() => Check::Correct,
+            Some(expected) => Check::Incorrect(expected.to_string()),
+            None => Check::Unknown,
+        }
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (part, answer) in self.parts.iter() {
+            writeln!(f, "part{}: {}", part, answer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("part1: 11\npart2: co,de,ka,ta\n");
+
+        assert_eq!(Some("11"), answers.get(1));
+        assert_eq!(Some("co,de,ka,ta"), answers.get(2));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.set(2, &Answer::from(31));
+        answers.set(1, &Answer::from(11));
+
+        assert_eq!("part1: 11\npart2: 31\n", answers.to_string());
+        assert_eq!(answers, Answers::parse(&answers.to_string()));
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("part1: 11");
+
+        assert_eq!(Check::Correct, answers.check(1, &Answer::from(11)));
+        assert_eq!(
+            Check::Incorrect("11".to_string()),
+            answers.check(1, &Answer::from(12))
+        );
+        assert_eq!(Check::Unknown, answers.check(2, &Answer::from(31)));
+    }
+}
@@ -0,0 +1,135 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use tracing::debug;
+
+use crate::{
+    context::Context,
+    error::{AocError, Result},
+    Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarize the samples, or `None` when there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(|d| d.as_secs_f64()).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Some(Self {
+            iterations: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} runs: min {:?}, median {:?}, mean {:?}, stddev {:?}",
+            self.iterations, self.min, self.median, self.mean, self.stddev
+        )
+    }
+}
+
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Run the solution `iterations` times, stopping at the first error.
+pub fn bench(solution: Solution, input: &str, ctx: &Context, iterations: usize) -> Result<Stats> {
+    let mut samples = Vec::with_capacity(iterations);
+    for i in 0..iterations {
+        let (result, elapsed) = time(|| solution(input, ctx));
+        result?;
+        debug!("Iteration {} took {:?}", i, elapsed);
+        samples.push(elapsed);
+    }
+
+    Stats::from_samples(&samples)
+        .ok_or_else(|| AocError::invalid("Benchmarking needs at least one iteration"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(v: u64) -> Duration {
+        Duration::from_millis(v)
+    }
+
+    #[test]
+    fn test_stats_odd_samples() {
+        let stats = Stats::from_samples(&[ms(3), ms(1), ms(2)]).unwrap();
+
+        assert_eq!(3, stats.iterations);
+        assert_eq!(ms(1), stats.min);
+        assert_eq!(ms(2), stats.median);
+        assert_eq!(ms(2), stats.mean);
+    }
+
+    #[test]
+    fn test_stats_even_samples() {
+        let stats = Stats::from_samples(&[ms(4), ms(2), ms(4), ms(6)]).unwrap();
+
+        assert_eq!(ms(2), stats.min);
+        assert_eq!(ms(4), stats.median);
+        assert_eq!(ms(4), stats.mean);
+        assert_eq!(1414, stats.stddev.as_micros());
+    }
+
+    #[test]
+    fn test_stats_no_samples() {
+        assert_eq!(None, Stats::from_samples(&[]));
+    }
+
+    #[test]
+    fn test_bench_runs_solution() {
+        let stats = bench(|s, _| Ok(s.into()), "abc", &Context::new(), 5).unwrap();
+        assert_eq!(5, stats.iterations);
+        assert!(bench(|s, _| Ok(s.into()), "abc", &Context::new(), 0).is_err());
+    }
+
+    #[test]
+    fn test_bench_stops_on_error() {
+        let err = bench(
+            |_, _| Err(AocError::no_solution("stuck")),
+            "abc",
+            &Context::new(),
+            5,
+        )
+        .unwrap_err();
+        assert_eq!("no solution: stuck", err.to_string());
+    }
+}
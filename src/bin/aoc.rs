@@ -0,0 +1,149 @@
+use std::{
+    path::{Path, PathBuf},
+    process,
+};
+
+use aoc2024::{
+    context::Context,
+    days::{self, Day},
+    input, Solution,
+};
+use clap::Parser;
+use tracing::{error, info, warn};
+
+#[derive(Debug, Parser)]
+struct Args {
+    #[command(flatten)]
+    verbosity: clap_verbosity_flag::Verbosity,
+
+    /// Directory containing day_NN.txt input files
+    #[arg(short, long, default_value = input::INPUTS_DIR)]
+    inputs: PathBuf,
+
+    /// Days to run. Accepts a single day (16), an inclusive range (1..=25) or "all"
+    #[arg(required = true, value_parser = parse_selection)]
+    days: Vec<Vec<u32>>,
+}
+
+fn parse_selection(s: &str) -> Result<Vec<u32>, String> {
+    let parse_day = |d: &str| {
+        d.trim()
+            .parse::<u32>()
+            .map_err(|_| format!("Invalid day: {}", d))
+    };
+
+    let selected: Vec<u32> = if s == "all" {
+        days::DAYS.iter().map(|d| d.number).collect()
+    } else if let Some((start, end)) = s.split_once("..=") {
+        (parse_day(start)?..=parse_day(end)?).collect()
+    } else {
+        vec![parse_day(s)?]
+    };
+
+    match selected.iter().find(|d| days::get(**d).is_none()) {
+        Some(d) => Err(format!("Day {} is not registered", d)),
+        None if selected.is_empty() => Err(format!("Empty day range: {}", s)),
+        None => Ok(selected),
+    }
+}
+
+struct Row {
+    day: u32,
+    part1: String,
+    part2: String,
+    failed: bool,
+}
+
+fn solve(day: &Day, inputs: &Path) -> Row {
+    let path = inputs.join(format!("day_{:02}.txt", day.number));
+
+    let contents = match input::read_input(&path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            warn!("Failed to read {}: {}", path.to_str().unwrap(), e);
+            return Row {
+                day: day.number,
+                part1: "missing input".to_string(),
+                part2: "missing input".to_string(),
+                failed: true,
+            };
+        }
+    };
+
+    info!("Solving day {}", day.number);
+
+    let solve = |part: u32, solution: Solution| match solution(&contents, &Context::new()) {
+        Ok(answer) => Ok(answer.to_string()),
+        Err(e) => {
+            error!("Day {} part {} failed: {}", day.number, part, e);
+            Err(format!("error: {}", e))
+        }
+    };
+
+    let part1 = solve(1, day.part1);
+    let part2 = day.part2.map_or(Ok("-".to_string()), |p| solve(2, p));
+
+    Row {
+        day: day.number,
+        failed: part1.is_err() || part2.is_err(),
+        part1: part1.unwrap_or_else(|e| e),
+        part2: part2.unwrap_or_else(|e| e),
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    let w1 = rows.iter().map(|r| r.part1.len()).max().unwrap_or(0).max(6);
+    let w2 = rows.iter().map(|r| r.part2.len()).max().unwrap_or(0).max(6);
+
+    println!("Day | {:<w1$} | Part 2", "Part 1");
+    println!("----+-{}-+-{}", "-".repeat(w1), "-".repeat(w2));
+    for row in rows {
+        println!("{:>3} | {:<w1$} | {}", row.day, row.part1, row.part2);
+    }
+}
+
+fn main() {
+    let args = Args::parse();
+
+    tracing_subscriber::fmt()
+        .with_max_level(args.verbosity)
+        .init();
+
+    let mut selected: Vec<u32> = args.days.iter().flatten().copied().collect();
+    selected.sort();
+    selected.dedup();
+
+    let rows: Vec<_> = selected
+        .iter()
+        .map(|d| solve(days::get(*d).unwrap(), &args.inputs))
+        .collect();
+
+    print_table(&rows);
+
+    if rows.iter().any(|r| r.failed) {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("16", vec![16])]
+    #[case("3..=5", vec![3, 4, 5])]
+    #[case("all", (1..=25).collect())]
+    fn test_parse_selection(#[case] input: &str, #[case] expected: Vec<u32>) {
+        assert_eq!(expected, parse_selection(input).unwrap());
+    }
+
+    #[rstest]
+    #[case("0")]
+    #[case("26")]
+    #[case("5..=3")]
+    #[case("abc")]
+    fn test_parse_invalid_selection(#[case] input: &str) {
+        assert!(parse_selection(input).is_err());
+    }
+}
@@ -0,0 +1,542 @@
+//! Circuits of two-input logic gates, as in day 24. Wires are identified by
+//! their index in the circuit, and every wire is either an input or driven by
+//! exactly one gate.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    str::FromStr,
+};
+
+mod adder;
+
+pub use adder::{Rule, Violation};
+
+use crate::{
+    error::{AocError, Result},
+    input::{Pattern, Section},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+impl Op {
+    /// Apply the gate to every bit of the inputs at once.
+    pub fn apply(self, a: u64, b: u64) -> u64 {
+        match self {
+            Op::And => a & b,
+            Op::Or => a | b,
+            Op::Xor => a ^ b,
+        }
+    }
+}
+
+impl FromStr for Op {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "AND" => Ok(Op::And),
+            "OR" => Ok(Op::Or),
+            "XOR" => Ok(Op::Xor),
+            x => Err(format!("Unsupported operation: {}", x)),
+        }
+    }
+}
+
+impl Display for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Op::And => write!(f, "AND"),
+            Op::Or => write!(f, "OR"),
+            Op::Xor => write!(f, "XOR"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gate {
+    pub op: Op,
+    pub inputs: [usize; 2],
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Circuit {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    gates: Vec<Option<Gate>>,
+}
+
+impl Circuit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse gates written as `x00 AND y00 -> z00`, one per line.
+    pub fn parse(section: &Section) -> Result<Self> {
+        let pattern = Pattern::new("{} {} {} -> {}");
+        let mut circuit = Self::new();
+
+        for line in section.lines() {
+            let fields: Vec<String> = pattern.extract(line)?;
+            let op = fields[1].parse().map_err(|e: String| line.error(e))?;
+            circuit
+                .add_gate(&fields[0], op, &fields[2], &fields[3])
+                .map_err(|e| line.error(e.to_string()))?;
+        }
+
+        Ok(circuit)
+    }
+
+    /// Id of the named wire, adding it if it doesn't exist yet.
+    pub fn add_wire(&mut self, name: &str) -> usize {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.gates.push(None);
+        id
+    }
+
+    pub fn add_gate(&mut self, a: &str, op: Op, b: &str, output: &str) -> Result<usize> {
+        let inputs = [self.add_wire(a), self.add_wire(b)];
+        let id = self.add_wire(output);
+
+        if self.gates[id].is_some() {
+            return Err(AocError::invalid(format!(
+                "Wire {} is driven by more than one gate",
+                output
+            )));
+        }
+
+        self.gates[id] = Some(Gate { op, inputs });
+        Ok(id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn wire(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, wire: usize) -> &str {
+        &self.names[wire]
+    }
+
+    /// The gate driving a wire, or `None` for inputs.
+    pub fn gate(&self, wire: usize) -> Option<Gate> {
+        self.gates[wire]
+    }
+
+    /// The gate driving a wire written as in the input, e.g. `x00 AND y00 ->
+    /// z00`, or just the name for inputs.
+    pub fn describe(&self, wire: usize) -> String {
+        match self.gates[wire] {
+            Some(Gate { op, inputs: [a, b] }) => {
+                format!(
+                    "{} {} {} -> {}",
+                    self.name(a),
+                    op,
+                    self.name(b),
+                    self.name(wire)
+                )
+            }
+            None => self.name(wire).to_string(),
+        }
+    }
+
+    pub fn gates(&self) -> impl Iterator<Item = (usize, Gate)> + '_ {
+        self.gates
+            .iter()
+            .enumerate()
+            .filter_map(|(wire, gate)| gate.map(|g| (wire, g)))
+    }
+
+    /// Wires named `prefix` followed by a bit number, such as `x00`, indexed
+    /// from the least significant bit. Fails unless the bits run from 0 with
+    /// no gaps or repeats.
+    pub fn bus(&self, prefix: char) -> Result<Vec<usize>> {
+        let mut wires: Vec<(usize, usize)> = self
+            .names
+            .iter()
+            .enumerate()
+            .filter_map(|(id, name)| {
+                let bit = name.strip_prefix(prefix)?.parse().ok()?;
+                Some((bit, id))
+            })
+            .collect();
+
+        wires.sort();
+        for (i, (bit, id)) in wires.iter().enumerate() {
+            if *bit < i {
+                return Err(AocError::invalid(format!(
+                    "Bus {} repeats bit {} in {}",
+                    prefix,
+                    bit,
+                    self.name(*id)
+                )));
+            }
+            if *bit > i {
+                return Err(AocError::invalid(format!(
+                    "Bus {} is missing bit {}",
+                    prefix, i
+                )));
+            }
+        }
+
+        Ok(wires.into_iter().map(|(_, id)| id).collect())
+    }
+
+    /// Exchange the gates driving two wires.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.gates.swap(a, b);
+    }
+
+    /// A wire and every wire it depends on.
+    pub fn fan_in(&self, wire: usize) -> HashSet<usize> {
+        let mut result = HashSet::new();
+        let mut to_visit = vec![wire];
+
+        while let Some(w) = to_visit.pop() {
+            if result.insert(w) {
+                if let Some(gate) = self.gates[w] {
+                    to_visit.extend(gate.inputs);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Every wire, ordered so that gate inputs come before their outputs.
+    pub fn topological_order(&self) -> Result<Vec<usize>> {
+        let mut pending: Vec<usize> = self
+            .gates
+            .iter()
+            .map(|g| g.map_or(0, |g| g.inputs.len()))
+            .collect();
+        let mut consumers = vec![Vec::new(); self.len()];
+        for (wire, gate) in self.gates() {
+            for input in gate.inputs {
+                consumers[input].push(wire);
+            }
+        }
+
+        let mut order: Vec<usize> = (0..self.len()).filter(|w| pending[*w] == 0).collect();
+        let mut i = 0;
+        while i < order.len() {
+            for &next in consumers[order[i]].iter() {
+                pending[next] -= 1;
+                if pending[next] == 0 {
+                    order.push(next);
+                }
+            }
+            i += 1;
+        }
+
+        if order.len() < self.len() {
+            let cycle = self.find_cycle(&pending);
+            let names: Vec<_> = cycle.iter().map(|w| self.name(*w)).collect();
+            return Err(AocError::invalid(format!(
+                "Circuit has a cycle: {}",
+                names.join(" -> ")
+            )));
+        }
+
+        Ok(order)
+    }
+
+    /// Follow unresolved inputs backwards from an unresolved wire until one
+    /// repeats, then return that loop in signal order.
+    fn find_cycle(&self, pending: &[usize]) -> Vec<usize> {
+        let mut wire = (0..self.len()).find(|w| pending[*w] > 0).unwrap();
+        let mut path = Vec::new();
+
+        loop {
+            if let Some(start) = path.iter().position(|w| *w == wire) {
+                let mut cycle = path[start..].to_vec();
+                cycle.reverse();
+                cycle.push(cycle[0]);
+                return cycle;
+            }
+            path.push(wire);
+
+            let gate = self.gates[wire].expect("Unresolved wires are driven by gates");
+            wire = *gate
+                .inputs
+                .iter()
+                .find(|w| pending[**w] > 0)
+                .expect("Unresolved gates have an unresolved input");
+        }
+    }
+
+    /// Value of every wire given the values of the inputs. Each bit of a value
+    /// is a separate lane, so up to 64 input vectors are evaluated at once.
+    pub fn evaluate(&self, inputs: &HashMap<usize, u64>) -> Result<Vec<u64>> {
+        let mut values = vec![0; self.len()];
+
+        for wire in self.topological_order()? {
+            values[wire] = match self.gates[wire] {
+                Some(gate) => gate
+                    .op
+                    .apply(values[gate.inputs[0]], values[gate.inputs[1]]),
+                None => *inputs.get(&wire).ok_or_else(|| {
+                    AocError::invalid(format!("No value for input {}", self.name(wire)))
+                })?,
+            };
+        }
+
+        Ok(values)
+    }
+
+    /// Evaluate the circuit for one or more numbers on each input bus, such as
+    /// `[('x', &[1, 2]), ('y', &[3, 4])]`, and read the numbers on the output
+    /// bus. Lane `i` of the result comes from the `i`th number of each input.
+    /// Each bus may be at most 64 wires wide.
+    pub fn evaluate_numbers(&self, inputs: &[(char, &[u64])], output: char) -> Result<Vec<u64>> {
+        let lanes = inputs.iter().map(|(_, n)| n.len()).max().unwrap_or(0);
+        if lanes > 64 {
+            return Err(AocError::invalid(
+                "At most 64 lanes can be evaluated at once",
+            ));
+        }
+
+        let bus = |prefix: char| {
+            let wires = self.bus(prefix)?;
+            match wires.len() {
+                0..=64 => Ok(wires),
+                n => Err(AocError::invalid(format!(
+                    "Bus {} has {} wires, at most 64 fit in a number",
+                    prefix, n
+                ))),
+            }
+        };
+
+        let mut values = HashMap::new();
+        for (prefix, numbers) in inputs {
+            for (bit, wire) in bus(*prefix)?.into_iter().enumerate() {
+                let word = numbers
+                    .iter()
+                    .enumerate()
+                    .fold(0, |acc, (lane, n)| acc | (((n >> bit) & 1) << lane));
+                values.insert(wire, word);
+            }
+        }
+
+        let bus = bus(output)?;
+        let values = self.evaluate(&values)?;
+        Ok((0..lanes)
+            .map(|lane| {
+                bus.iter()
+                    .enumerate()
+                    .fold(0, |acc, (bit, w)| acc | (((values[*w] >> lane) & 1) << bit))
+            })
+            .collect())
+    }
+
+    /// Graphviz rendering of the netlist. Wires in `highlight` are filled in
+    /// red, e.g. to show swapped outputs.
+    pub fn to_dot(&self, highlight: &[usize]) -> String {
+        let mut lines = vec![
+            "digraph circuit {".to_string(),
+            "    rankdir=LR;".to_string(),
+        ];
+
+        for (wire, name) in self.names.iter().enumerate() {
+            let mut attrs = match self.gates[wire] {
+                Some(gate) => {
+                    let color = match gate.op {
+                        Op::And => "lightblue",
+                        Op::Or => "lightyellow",
+                        Op::Xor => "lightgreen",
+                    };
+                    vec![
+                        format!("label=\"{}\\n{}\"", name, gate.op),
+                        format!("fillcolor={}", color),
+                        "style=filled".to_string(),
+                    ]
+                }
+                None => vec!["shape=box".to_string()],
+            };
+            if highlight.contains(&wire) {
+                attrs.retain(|a| !a.starts_with("fillcolor") && !a.starts_with("style"));
+                attrs.extend(["fillcolor=red".to_string(), "style=filled".to_string()]);
+            }
+            lines.push(format!("    \"{}\" [{}];", name, attrs.join(", ")));
+        }
+
+        for (wire, gate) in self.gates() {
+            for input in gate.inputs {
+                lines.push(format!(
+                    "    \"{}\" -> \"{}\";",
+                    self.name(input),
+                    self.name(wire)
+                ));
+            }
+        }
+
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn circuit(gates: &str) -> Circuit {
+        Circuit::parse(&Section::from(gates)).unwrap()
+    }
+
+    /// Two bit adder, listed out of order.
+    fn adder() -> Circuit {
+        circuit(
+            "c00 XOR s01 -> z01\n\
+             x00 XOR y00 -> z00\n\
+             x00 AND y00 -> c00\n\
+             x01 XOR y01 -> s01\n\
+             x01 AND y01 -> a01\n\
+             c00 AND s01 -> b01\n\
+             a01 OR b01 -> z02",
+        )
+    }
+
+    #[test]
+    fn test_parse() {
+        let c = adder();
+        assert_eq!(11, c.len());
+        assert_eq!(
+            vec![
+                c.wire("z00").unwrap(),
+                c.wire("z01").unwrap(),
+                c.wire("z02").unwrap()
+            ],
+            c.bus('z').unwrap()
+        );
+
+        assert_eq!("x01 AND y01 -> a01", c.describe(c.wire("a01").unwrap()));
+        assert_eq!("x01", c.describe(c.wire("x01").unwrap()));
+
+        let err = Circuit::parse(&Section::from("a AND b -> c\na NAND b -> d")).unwrap_err();
+        assert_eq!("line 2: Unsupported operation: NAND", err.to_string());
+
+        let err = Circuit::parse(&Section::from("a AND b -> c\na OR b -> c")).unwrap_err();
+        assert_eq!(
+            "line 2: invalid input: Wire c is driven by more than one gate",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_topological_order() {
+        let c = adder();
+        let order = c.topological_order().unwrap();
+
+        for (wire, gate) in c.gates() {
+            let position = |w| order.iter().position(|o| *o == w).unwrap();
+            assert!(gate.inputs.iter().all(|i| position(*i) < position(wire)));
+        }
+    }
+
+    #[test]
+    fn test_cycle() {
+        let c = circuit("x AND b -> a\na XOR y -> b\nb OR x -> z");
+        assert_eq!(
+            "invalid input: Circuit has a cycle: a -> b -> a",
+            c.topological_order().unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_evaluate_numbers() {
+        let c = adder();
+        let x: Vec<u64> = (0..16).map(|i| i % 4).collect();
+        let y: Vec<u64> = (0..16).map(|i| i / 4).collect();
+
+        let sums = c.evaluate_numbers(&[('x', &x), ('y', &y)], 'z').unwrap();
+        assert_eq!((0..16).map(|i| i % 4 + i / 4).collect::<Vec<_>>(), sums);
+
+        let err = c.evaluate_numbers(&[('x', &x)], 'z').unwrap_err();
+        assert_eq!("invalid input: No value for input y00", err.to_string());
+    }
+
+    #[test]
+    fn test_bus_gaps() {
+        let c = Circuit::parse(&Section::from(
+            "x00 AND y00 -> z00\nx00 OR y00 -> z01\nx00 XOR y00 -> z03",
+        ))
+        .unwrap();
+        assert_eq!(
+            "invalid input: Bus z is missing bit 2",
+            c.bus('z').unwrap_err().to_string()
+        );
+
+        let c = Circuit::parse(&Section::from("x00 AND y00 -> z00\nx00 OR y00 -> z0")).unwrap();
+        assert_eq!(
+            "invalid input: Bus z repeats bit 0 in z0",
+            c.bus('z').unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_evaluate_wide_bus() {
+        let mut c = Circuit::new();
+        for i in 0..65 {
+            c.add_gate(&format!("x{:02}", i), Op::Or, "y00", &format!("z{:02}", i))
+                .unwrap();
+        }
+
+        let err = c.evaluate_numbers(&[('x', &[1]), ('y', &[0])], 'z');
+        assert_eq!(
+            "invalid input: Bus x has 65 wires, at most 64 fit in a number",
+            err.unwrap_err().to_string()
+        );
+
+        let err = c.evaluate_numbers(&[('y', &[0])], 'z');
+        assert_eq!(
+            "invalid input: Bus z has 65 wires, at most 64 fit in a number",
+            err.unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_swap_and_fan_in() {
+        let mut c = adder();
+        let (z00, c00) = (c.wire("z00").unwrap(), c.wire("c00").unwrap());
+        assert_eq!(3, c.fan_in(z00).len());
+        assert_eq!(7, c.fan_in(c.wire("z01").unwrap()).len());
+
+        c.swap(z00, c00);
+        assert_eq!(
+            vec![2],
+            c.evaluate_numbers(&[('x', &[1]), ('y', &[0])], 'z')
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let c = circuit("x AND y -> z");
+        let z = c.wire("z").unwrap();
+
+        assert_eq!(
+            "digraph circuit {\n    rankdir=LR;\n    \"x\" [shape=box];\n    \"y\" [shape=box];\n    \
+             \"z\" [label=\"z\\nAND\", fillcolor=red, style=filled];\n    \"x\" -> \"z\";\n    \
+             \"y\" -> \"z\";\n}",
+            c.to_dot(&[z])
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use std::fmt::Display;
+
+use super::{Circuit, Op};
+use crate::error::{AocError, Result};
+
+/// Structural rules of a ripple-carry adder. For bit `i` it computes
+///
+/// ```text
+/// xi XOR yi -> si       xi AND yi -> ai
+/// si XOR ci -> zi       si AND ci -> bi       ai OR bi -> c(i+1)
+/// ```
+///
+/// where bit 0 has no carry in, so `x00 XOR y00 -> z00` and its AND is the
+/// first carry. The carry out of the top bit is the last output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rule {
+    OutputNotXor,
+    FinalCarryWrongGate,
+    FirstSumNotOutput,
+    InnerXorNotOutput,
+    HalfSumNotIntoXor,
+    AndNotIntoOr,
+    CarryNotIntoNextBit,
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            Rule::OutputNotXor => "output bits other than the last come from XOR gates",
+            Rule::FinalCarryWrongGate => "the last output bit is the carry out of the top bit",
+            Rule::FirstSumNotOutput => "x00 XOR y00 is the first output bit",
+            Rule::InnerXorNotOutput => "XOR gates that don't read inputs produce output bits",
+            Rule::HalfSumNotIntoXor => "x XOR y feeds the XOR producing the output bit",
+            Rule::AndNotIntoOr => "AND gates feed the OR producing the carry",
+            Rule::CarryNotIntoNextBit => "carries feed both an XOR and an AND of the next bit",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+/// A gate whose output wire breaks one of the adder rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Violation {
+    pub wire: usize,
+    pub rule: Rule,
+}
+
+impl Circuit {
+    /// Check every gate against the structure of a ripple-carry adder adding
+    /// the `x` and `y` buses into `z`, of any width. Returns the gates that
+    /// break a rule, in wire order, possibly more than once if they break
+    /// several.
+    pub fn verify_adder(&self) -> Result<Vec<Violation>> {
+        let (xs, ys, zs) = (self.bus('x')?, self.bus('y')?, self.bus('z')?);
+        let width = xs.len();
+        if width == 0 || ys.len() != width || zs.len() != width + 1 {
+            return Err(AocError::invalid(format!(
+                "An adder needs equally wide x and y buses and one more z bit, found {}, {} and {}",
+                xs.len(),
+                ys.len(),
+                zs.len()
+            )));
+        }
+        if let Some(w) = xs
+            .iter()
+            .chain(ys.iter())
+            .find(|w| self.gate(**w).is_some())
+        {
+            return Err(AocError::invalid(format!(
+                "Input {} is driven by a gate",
+                self.name(*w)
+            )));
+        }
+
+        let mut consumers = vec![Vec::new(); self.len()];
+        for (_, gate) in self.gates() {
+            for input in gate.inputs {
+                consumers[input].push(gate.op);
+            }
+        }
+        let feeds = |wire: usize, op: Op| consumers[wire].contains(&op);
+
+        let is_input = |wire: usize| self.gate(wire).is_none();
+        let first_bit = [xs[0], ys[0]];
+        let last = zs[width];
+
+        let mut violations = Vec::new();
+        for (wire, gate) in self.gates() {
+            let reads_inputs = gate.inputs.iter().all(|w| is_input(*w));
+            let first = gate.inputs.iter().all(|w| first_bit.contains(w));
+            let output = zs.contains(&wire);
+            let mut broken = |rule| violations.push(Violation { wire, rule });
+
+            if output && wire != last && gate.op != Op::Xor {
+                broken(Rule::OutputNotXor);
+            }
+            if wire == last {
+                let expected = if width == 1 { Op::And } else { Op::Or };
+                if gate.op != expected {
+                    broken(Rule::FinalCarryWrongGate);
+                }
+            }
+
+            match gate.op {
+                Op::Xor if first => {
+                    if wire != zs[0] {
+                        broken(Rule::FirstSumNotOutput);
+                    }
+                }
+                Op::Xor if reads_inputs => {
+                    if !feeds(wire, Op::Xor) {
+                        broken(Rule::HalfSumNotIntoXor);
+                    }
+                }
+                Op::Xor => {
+                    if !output {
+                        broken(Rule::InnerXorNotOutput);
+                    }
+                }
+                Op::And if first => {
+                    if width > 1 && !(feeds(wire, Op::Xor) && feeds(wire, Op::And)) {
+                        broken(Rule::CarryNotIntoNextBit);
+                    }
+                }
+                Op::And => {
+                    if !feeds(wire, Op::Or) {
+                        broken(Rule::AndNotIntoOr);
+                    }
+                }
+                Op::Or => {
+                    if wire != last && !(feeds(wire, Op::Xor) && feeds(wire, Op::And)) {
+                        broken(Rule::CarryNotIntoNextBit);
+                    }
+                }
+            }
+        }
+
+        violations.sort_by_key(|v| v.wire);
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ripple-carry adder of the given width, with gate outputs named after
+    /// their role.
+    fn adder(width: usize) -> Circuit {
+        let mut c = Circuit::new();
+        let z = |i: usize| format!("z{:02}", i);
+        let carry = |i: usize| {
+            if i == width {
+                z(i)
+            } else {
+                format!("c{:02}", i)
+            }
+        };
+
+        c.add_gate("x00", Op::Xor, "y00", &z(0)).unwrap();
+        c.add_gate("x00", Op::And, "y00", &carry(1)).unwrap();
+        for i in 1..width {
+            let (x, y) = (format!("x{:02}", i), format!("y{:02}", i));
+            let (s, a, b) = (
+                format!("s{:02}", i),
+                format!("a{:02}", i),
+                format!("b{:02}", i),
+            );
+            c.add_gate(&x, Op::Xor, &y, &s).unwrap();
+            c.add_gate(&x, Op::And, &y, &a).unwrap();
+            c.add_gate(&s, Op::Xor, &carry(i), &z(i)).unwrap();
+            c.add_gate(&carry(i), Op::And, &s, &b).unwrap();
+            c.add_gate(&a, Op::Or, &b, &carry(i + 1)).unwrap();
+        }
+        c
+    }
+
+    fn swap(c: &mut Circuit, a: &str, b: &str) {
+        c.swap(c.wire(a).unwrap(), c.wire(b).unwrap());
+    }
+
+    fn suspects(c: &Circuit) -> Vec<(String, Rule)> {
+        c.verify_adder()
+            .unwrap()
+            .iter()
+            .map(|v| (c.name(v.wire).to_string(), v.rule))
+            .collect()
+    }
+
+    #[test]
+    fn test_correct_adders() {
+        for width in [1, 2, 5, 45] {
+            let c = adder(width);
+            assert!(c.verify_adder().unwrap().is_empty(), "width {}", width);
+
+            let x = (1 << width) - 1;
+            let sums = c.evaluate_numbers(&[('x', &[x, 5 % (x + 1)]), ('y', &[1, x])], 'z');
+            assert_eq!(vec![x + 1, 5 % (x + 1) + x], sums.unwrap());
+        }
+    }
+
+    #[test]
+    fn test_swapped_output_and_carry() {
+        let mut c = adder(8);
+        swap(&mut c, "z03", "c04");
+
+        let expected = [
+            ("z03", Rule::OutputNotXor),
+            ("z03", Rule::CarryNotIntoNextBit),
+            ("c04", Rule::InnerXorNotOutput),
+        ];
+        assert_eq!(
+            expected.map(|(n, r)| (n.to_string(), r)).to_vec(),
+            suspects(&c)
+        );
+    }
+
+    #[test]
+    fn test_swapped_half_sum_and_carry() {
+        let mut c = adder(8);
+        swap(&mut c, "s05", "a05");
+        swap(&mut c, "z07", "b07");
+
+        let mut names: Vec<_> = suspects(&c).into_iter().map(|(n, _)| n).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(vec!["a05", "b07", "s05", "z07"], names);
+    }
+
+    #[test]
+    fn test_first_bit() {
+        let mut c = adder(4);
+        swap(&mut c, "z00", "c01");
+
+        let s = suspects(&c);
+        assert!(s.contains(&("c01".to_string(), Rule::FirstSumNotOutput)));
+        assert!(s.contains(&("z00".to_string(), Rule::OutputNotXor)));
+    }
+
+    #[test]
+    fn test_not_an_adder() {
+        let c = Circuit::parse(&"x00 AND y00 -> z00".into()).unwrap();
+        assert_eq!(
+            "invalid input: An adder needs equally wide x and y buses and one more z bit, \
+             found 1, 1 and 1",
+            c.verify_adder().unwrap_err().to_string()
+        );
+    }
+}
@@ -1 +1,3 @@
 pub mod grid;
+pub mod point;
+pub mod sparse_grid;
@@ -1,18 +1,41 @@
-use std::iter::repeat_n;
+mod regions;
+mod render;
+mod transform;
+
+use std::{collections::HashMap, fmt::Display, iter::repeat_n};
 
 use lazy_static::lazy_static;
 
+use crate::{
+    collections::point::Point,
+    error::{AocError, Result},
+    input::{self, Line},
+};
+
+pub use regions::{Region, Regions};
+pub use render::{Color, Location, Render};
+pub use transform::{Rect, View};
+
+/// Values are stored in a single row-major `Vec`.
 #[derive(Debug, Clone)]
 pub struct Grid<T> {
-    values: Vec<Vec<T>>,
+    values: Vec<T>,
+    rows: usize,
+    cols: usize,
 }
 
+/// The cardinal directions come first so that `dir as usize` indexes them
+/// from 0 to 3.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Direction {
     North,
     East,
     South,
     West,
+    NorthEast,
+    SouthEast,
+    SouthWest,
+    NorthWest,
 }
 
 lazy_static! {
@@ -22,6 +45,23 @@ lazy_static! {
         Direction::South,
         Direction::West
     ];
+    pub static ref INTERCARDINAL_DIRECTIONS: Vec<Direction> = vec![
+        Direction::NorthEast,
+        Direction::SouthEast,
+        Direction::SouthWest,
+        Direction::NorthWest
+    ];
+    /// All eight directions, clockwise from north.
+    pub static ref ALL_DIRECTIONS: Vec<Direction> = vec![
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest
+    ];
 }
 
 pub fn get_direction_delta(dir: Direction) -> (isize, isize) {
@@ -30,16 +70,89 @@ pub fn get_direction_delta(dir: Direction) -> (isize, isize) {
         Direction::East => (0, 1),
         Direction::South => (1, 0),
         Direction::West => (0, -1),
+        Direction::NorthEast => (-1, 1),
+        Direction::SouthEast => (1, 1),
+        Direction::SouthWest => (1, -1),
+        Direction::NorthWest => (-1, -1),
+    }
+}
+
+impl Direction {
+    /// Rotate 90 degrees clockwise.
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+            Direction::NorthEast => Direction::SouthEast,
+            Direction::SouthEast => Direction::SouthWest,
+            Direction::SouthWest => Direction::NorthWest,
+            Direction::NorthWest => Direction::NorthEast,
+        }
+    }
+
+    /// Rotate 90 degrees counter-clockwise.
+    pub fn turn_left(&self) -> Self {
+        self.reverse().turn_right()
+    }
+
+    pub fn reverse(&self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// The two directions at right angles, clockwise first.
+    pub fn perpendiculars(&self) -> [Self; 2] {
+        [self.turn_right(), self.turn_left()]
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = AocError;
+
+    /// Accepts arrows (`^>v<`), compass points (`NESW`) and `UDLR`.
+    fn try_from(value: char) -> Result<Self> {
+        match value {
+            '^' | 'N' | 'U' => Ok(Direction::North),
+            '>' | 'E' | 'R' => Ok(Direction::East),
+            'v' | 'S' | 'D' => Ok(Direction::South),
+            '<' | 'W' | 'L' => Ok(Direction::West),
+            c => Err(AocError::invalid(format!("Unrecognized direction: {}", c))),
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let arrow = match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+            Direction::NorthEast => '↗',
+            Direction::SouthEast => '↘',
+            Direction::SouthWest => '↙',
+            Direction::NorthWest => '↖',
+        };
+        write!(f, "{}", arrow)
     }
 }
 
-#[derive(Clone, Copy)]
 pub struct Position<'a, T> {
     row: usize,
     col: usize,
     grid: &'a Grid<T>,
 }
 
+// Derived impls would require `T: Copy`, but a position only borrows the grid.
+impl<T> Clone for Position<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Position<'_, T> {}
+
 impl<'a, T> Position<'a, T> {
     pub fn row(&self) -> usize {
         self.row
@@ -78,17 +191,126 @@ where
     I: Iterator<Item = T>,
 {
     fn from_iter<B: IntoIterator<Item = I>>(items: B) -> Self {
-        let values: Vec<Vec<_>> = items.into_iter().map(|item| item.collect()).collect();
+        let mut values = Vec::new();
+        let mut rows = 0;
+        let mut cols = 0;
+
+        for item in items {
+            let start = values.len();
+            values.extend(item);
+
+            let length = values.len() - start;
+            if rows == 0 {
+                cols = length;
+            } else if length != cols {
+                panic!(
+                    "All rows must have the same number of columns. Expected count {}",
+                    cols
+                );
+            }
+            rows += 1;
+        }
 
-        let length = values[0].len();
-        if !values.iter().all(|v| v.len() == length) {
-            panic!(
-                "All rows must have the same number of columns. Expected count {}",
-                length
-            );
+        Grid { values, rows, cols }
+    }
+}
+
+/// Positions of marker characters, such as a start `S`, that were replaced
+/// while parsing a grid.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Markers {
+    positions: HashMap<char, Vec<(usize, usize)>>,
+}
+
+impl Markers {
+    pub fn get(&self, marker: char) -> &[(usize, usize)] {
+        self.positions.get(&marker).map_or(&[], |p| p.as_slice())
+    }
+
+    /// Position of a marker that must appear exactly once.
+    pub fn single(&self, marker: char) -> Result<(usize, usize)> {
+        match self.get(marker) {
+            [position] => Ok(*position),
+            positions => Err(AocError::invalid(format!(
+                "Expected one {:?} marker, found {}",
+                marker,
+                positions.len()
+            ))),
         }
+    }
+}
+
+impl<T> Grid<T> {
+    pub(crate) fn from_lines(
+        lines: &[Line],
+        mut cell: impl FnMut(usize, usize, char) -> std::result::Result<T, String>,
+    ) -> Result<Self> {
+        let cols = match lines.first() {
+            Some(line) => line.text.chars().count(),
+            None => return Err(AocError::invalid("Empty grid")),
+        };
+
+        let mut values = Vec::with_capacity(lines.len() * cols);
+        for (row, line) in lines.iter().enumerate() {
+            let mut count = 0;
+            for (col, (offset, c)) in line.text.char_indices().enumerate() {
+                values.push(cell(row, col, c).map_err(|e| line.error_at(offset, e))?);
+                count += 1;
+            }
+
+            if count != cols {
+                return Err(line.error(format!("Expected {} columns, found {}", cols, count)));
+            }
+        }
+
+        Ok(Grid {
+            values,
+            rows: lines.len(),
+            cols,
+        })
+    }
 
-        Grid { values }
+    /// Parse one cell per character, mapping each with `cell`.
+    pub fn parse_with(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self> {
+        let lines: Vec<_> = input::lines(input).collect();
+        Self::from_lines(&lines, |_, _, c| Ok(cell(c)))
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: TryFrom<char>,
+{
+    pub fn parse(input: &str) -> Result<Self> {
+        input::parse_grid(input)
+    }
+
+    /// Parse a grid, recording where each of the `markers` appears and
+    /// replacing it with `floor`.
+    pub fn parse_with_markers(input: &str, markers: &str, floor: char) -> Result<(Self, Markers)> {
+        let lines: Vec<_> = input::lines(input).collect();
+        Self::from_lines_with_markers(&lines, markers, floor)
+    }
+
+    pub(crate) fn from_lines_with_markers(
+        lines: &[Line],
+        markers: &str,
+        floor: char,
+    ) -> Result<(Self, Markers)> {
+        let mut found = Markers::default();
+
+        let grid = Self::from_lines(lines, |row, col, c| {
+            let c = if markers.contains(c) {
+                found.positions.entry(c).or_default().push((row, col));
+                floor
+            } else {
+                c
+            };
+
+            T::try_from(c).map_err(|_| format!("Unexpected character {:?}", c))
+        })?;
+
+        Ok((grid, found))
     }
 }
 
@@ -97,7 +319,11 @@ where
     T: Copy,
 {
     pub fn new(rows: usize, cols: usize, init: T) -> Self {
-        (0..rows).map(|_| repeat_n(init, cols)).collect()
+        Grid {
+            values: repeat_n(init, rows * cols).collect(),
+            rows,
+            cols,
+        }
     }
 }
 
@@ -105,7 +331,7 @@ impl<T> Grid<T>
 where
     T: Eq,
 {
-    pub fn find(&self, value: &T) -> Option<Position<T>> {
+    pub fn find(&self, value: &T) -> Option<Position<'_, T>> {
         self.enumerate().find_map(|(r, c, v)| match v {
             t if t == value => self.position(r, c),
             _ => None,
@@ -115,39 +341,47 @@ where
 
 impl<T> Grid<T> {
     pub fn get(&self, row: usize, col: usize) -> &T {
-        &self.values[row][col]
+        &self.row(row)[col]
     }
 
     pub fn get_mut(&mut self, row: usize, col: usize) -> &mut T {
-        &mut self.values[row][col]
+        &mut self.row_mut(row)[col]
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.values[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.values[row * self.cols..(row + 1) * self.cols]
     }
 
     pub fn enumerate(&self) -> impl Iterator<Item = (usize, usize, &T)> {
-        self.values.iter().enumerate().flat_map(|(row, v)| {
-            v.iter()
-                .enumerate()
-                .map(move |(col, value)| (row, col, value))
-        })
+        let cols = self.cols;
+        self.values
+            .iter()
+            .enumerate()
+            .map(move |(i, value)| (i / cols, i % cols, value))
     }
 
     pub fn enumerate_mut(&mut self) -> impl Iterator<Item = (usize, usize, &mut T)> {
-        self.values.iter_mut().enumerate().flat_map(|(row, v)| {
-            v.iter_mut()
-                .enumerate()
-                .map(move |(col, value)| (row, col, value))
-        })
+        let cols = self.cols;
+        self.values
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, value)| (i / cols, i % cols, value))
     }
 
     pub fn rows(&self) -> usize {
-        self.values.len()
+        self.rows
     }
 
     pub fn cols(&self) -> usize {
-        self.values[0].len()
+        self.cols
     }
 
     pub fn row_wise_iter(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
-        self.values.iter().map(|v| v.iter())
+        (0..self.rows()).map(move |r| self.row(r).iter())
     }
 
     pub fn col_wise_iter(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
@@ -165,11 +399,20 @@ impl<T> Grid<T> {
             )
         }
 
-        self.values.insert(row, new_row);
+        let at = row * self.cols;
+        self.values.splice(at..at, new_row);
+        self.rows += 1;
     }
 
     pub fn insert_col_at(&mut self, col: usize, values: impl IntoIterator<Item = T>) {
-        let mut new_col: Vec<_> = values.into_iter().take(self.rows()).collect();
+        assert!(
+            col <= self.cols,
+            "Column out of bounds. Expected at most {}, Found {}",
+            self.cols,
+            col
+        );
+
+        let new_col: Vec<_> = values.into_iter().take(self.rows()).collect();
 
         if new_col.len() != self.rows() {
             panic!(
@@ -179,13 +422,19 @@ impl<T> Grid<T> {
             )
         }
 
-        self.values
-            .iter_mut()
-            .rev()
-            .for_each(|v| v.insert(col, new_col.pop().unwrap()));
+        let cols = self.cols;
+        let mut old_values = std::mem::take(&mut self.values).into_iter();
+        self.values = Vec::with_capacity(self.rows * (cols + 1));
+
+        for value in new_col {
+            self.values.extend(old_values.by_ref().take(col));
+            self.values.push(value);
+            self.values.extend(old_values.by_ref().take(cols - col));
+        }
+        self.cols += 1;
     }
 
-    pub fn position<U: TryInto<usize>>(&self, row: U, col: U) -> Option<Position<T>> {
+    pub fn position<U: TryInto<usize>>(&self, row: U, col: U) -> Option<Position<'_, T>> {
         match (row.try_into(), col.try_into()) {
             (Ok(r), Ok(c)) if r < self.rows() && c < self.cols() => Some(Position {
                 row: r,
@@ -196,7 +445,7 @@ impl<T> Grid<T> {
         }
     }
 
-    pub fn positions(&self) -> impl Iterator<Item = Position<T>> {
+    pub fn positions(&self) -> impl Iterator<Item = Position<'_, T>> {
         self.enumerate().map(|(row, col, _)| Position {
             row,
             col,
@@ -205,10 +454,38 @@ impl<T> Grid<T> {
     }
 
     pub fn map<U>(&self, map_fn: fn(&T) -> U) -> Grid<U> {
-        self.values
-            .iter()
-            .map(|row_values| row_values.iter().map(map_fn))
-            .collect()
+        Grid {
+            values: self.values.iter().map(map_fn).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Display,
+{
+    pub fn render(&self) -> Render<'_, T> {
+        self.render_with(|v| v.to_string())
+    }
+}
+
+impl<T> Grid<T> {
+    /// Render with a custom cell representation, e.g. for non-printable cells.
+    pub fn render_with<'a>(&'a self, cell: impl Fn(&T) -> String + 'a) -> Render<'a, T> {
+        Render::new(self.rows, self.cols, Point::ZERO, move |p| {
+            cell(self.get(p.y as usize, p.x as usize))
+        })
+    }
+}
+
+impl<T> Display for Grid<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render())
     }
 }
 
@@ -219,18 +496,56 @@ mod tests {
 
     #[fixture]
     fn grid() -> Grid<char> {
-        let lines = vec!["abc", "def", "ghi", "jkl"];
+        let lines = ["abc", "def", "ghi", "jkl"];
         lines.iter().map(|l| l.chars()).collect()
     }
 
     #[rstest]
     fn test_direct_values(grid: Grid<char>) {
         assert!(!grid.values.is_empty());
-        assert_eq!(grid.values.len(), 4);
-        assert_eq!(grid.values[0].len(), 3);
-        assert_eq!(grid.values[0][0], 'a');
-        assert_eq!(grid.values[0][1], 'b');
-        assert_eq!(grid.values[1][1], 'e');
+        assert_eq!(grid.values.len(), 12);
+        assert_eq!(grid.rows, 4);
+        assert_eq!(grid.cols, 3);
+        assert_eq!(grid.values[0], 'a');
+        assert_eq!(grid.values[1], 'b');
+        assert_eq!(grid.values[4], 'e');
+    }
+
+    #[rstest]
+    fn test_row(grid: Grid<char>) {
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+    }
+
+    #[rstest]
+    fn test_parse() {
+        let grid = Grid::<char>::parse("ab\ncd\n").unwrap();
+        assert_eq!(2, grid.rows());
+        assert_eq!(&'c', grid.get(1, 0));
+
+        let err = Grid::<char>::parse("ab\nc").unwrap_err();
+        assert_eq!("line 2: Expected 2 columns, found 1", err.to_string());
+    }
+
+    #[rstest]
+    fn test_parse_with() {
+        let grid = Grid::parse_with("09\n12", |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!(&9, grid.get(0, 1));
+    }
+
+    #[rstest]
+    fn test_parse_with_markers() {
+        let (grid, markers) = Grid::<char>::parse_with_markers("S.#\n.#E\nE..", "SE", '.').unwrap();
+
+        assert_eq!(&'.', grid.get(0, 0));
+        assert_eq!(Ok((0, 0)), markers.single('S'));
+        assert_eq!(&[(1, 2), (2, 0)], markers.get('E'));
+        assert!(markers.single('E').is_err());
+        assert!(markers.get('^').is_empty());
+    }
+
+    #[rstest]
+    fn test_display(grid: Grid<char>) {
+        assert_eq!("abc\ndef\nghi\njkl", grid.to_string());
     }
 
     #[rstest]
@@ -260,20 +575,20 @@ mod tests {
     #[rstest]
     #[should_panic]
     fn test_invalid_collect() {
-        let lines = vec!["abc", "defg"];
+        let lines = ["abc", "defg"];
         let _: Grid<_> = lines.iter().map(|l| l.chars()).collect();
     }
 
     #[rstest]
     fn test_row_wise_iter(grid: Grid<char>) {
-        let row: Vec<_> = grid.row_wise_iter().next().unwrap().map(|c| *c).collect();
+        let row: Vec<_> = grid.row_wise_iter().next().unwrap().copied().collect();
 
         assert_eq!(row, vec!['a', 'b', 'c']);
     }
 
     #[rstest]
     fn test_col_wise_iter(grid: Grid<char>) {
-        let col: Vec<_> = grid.col_wise_iter().next().unwrap().map(|c| *c).collect();
+        let col: Vec<_> = grid.col_wise_iter().next().unwrap().copied().collect();
 
         assert_eq!(col, vec!['a', 'd', 'g', 'j']);
     }
@@ -283,6 +598,8 @@ mod tests {
         grid.insert_row_at(1, "123".chars());
 
         assert_eq!(*grid.get(1, 0), '1');
+        assert_eq!(*grid.get(2, 0), 'd');
+        assert_eq!(grid.rows(), 5);
     }
 
     #[rstest]
@@ -290,6 +607,17 @@ mod tests {
         grid.insert_col_at(1, "1234".chars());
 
         assert_eq!(*grid.get(0, 1), '1');
+        assert_eq!(grid.row(3), &['j', '4', 'k', 'l']);
+        assert_eq!(grid.cols(), 4);
+
+        grid.insert_col_at(4, "5678".chars());
+        assert_eq!(grid.row(0), &['a', '1', 'b', 'c', '5']);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Column out of bounds")]
+    fn test_insert_col_past_end(mut grid: Grid<char>) {
+        grid.insert_col_at(4, "1234".chars());
     }
 
     #[rstest]
@@ -349,6 +677,75 @@ mod tests {
         assert_eq!(value, *neighbor.value());
     }
 
+    #[rstest]
+    #[case(1, 1, Direction::NorthEast, 'c')]
+    #[case(1, 1, Direction::SouthEast, 'i')]
+    #[case(1, 1, Direction::SouthWest, 'g')]
+    #[case(1, 1, Direction::NorthWest, 'a')]
+    fn test_diagonal_neighbors(
+        grid: Grid<char>,
+        #[case] row: usize,
+        #[case] col: usize,
+        #[case] dir: Direction,
+        #[case] value: char,
+    ) {
+        let pos = grid.position(row, col).unwrap();
+        assert_eq!(value, *pos.get_neighbor(dir).unwrap().value());
+    }
+
+    #[rstest]
+    fn test_all_neighbors(grid: Grid<char>) {
+        let corner = grid.position(0, 0).unwrap();
+        let values: String = corner
+            .get_neighbors(&ALL_DIRECTIONS)
+            .map(|n| *n.value())
+            .collect();
+        assert_eq!("bed", values);
+
+        let center = grid.position(1, 1).unwrap();
+        assert_eq!(8, center.get_neighbors(&ALL_DIRECTIONS).count());
+    }
+
+    #[rstest]
+    #[case(Direction::North, Direction::East)]
+    #[case(Direction::West, Direction::North)]
+    #[case(Direction::NorthEast, Direction::SouthEast)]
+    #[case(Direction::NorthWest, Direction::NorthEast)]
+    fn test_turns(#[case] dir: Direction, #[case] right: Direction) {
+        assert_eq!(right, dir.turn_right());
+        assert_eq!(dir, right.turn_left());
+        assert_eq!(dir, dir.reverse().reverse());
+        assert_ne!(dir, dir.reverse());
+    }
+
+    #[rstest]
+    fn test_perpendiculars() {
+        assert_eq!(
+            [Direction::East, Direction::West],
+            Direction::North.perpendiculars()
+        );
+        assert_eq!(
+            [Direction::North, Direction::South],
+            Direction::West.perpendiculars()
+        );
+    }
+
+    #[rstest]
+    #[case('^', Direction::North)]
+    #[case('E', Direction::East)]
+    #[case('D', Direction::South)]
+    #[case('<', Direction::West)]
+    fn test_direction_from_char(#[case] c: char, #[case] dir: Direction) {
+        assert_eq!(dir, Direction::try_from(c).unwrap());
+    }
+
+    #[rstest]
+    fn test_direction_display() {
+        let arrows: String = CARDINAL_DIRECTIONS.iter().map(|d| d.to_string()).collect();
+        assert_eq!("^>v<", arrows);
+        assert!(Direction::try_from('x').is_err());
+    }
+
     #[rstest]
     fn test_map(grid: Grid<char>) {
         let mapped = grid.map(|c| c.to_ascii_uppercase());
@@ -0,0 +1,168 @@
+use super::{get_direction_delta, Grid, CARDINAL_DIRECTIONS};
+
+/// A connected group of cells. Every region is bounded by as many sides as it
+/// has corners, so `sides` also counts corners.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub id: usize,
+    pub cells: Vec<(usize, usize)>,
+    pub perimeter: usize,
+    pub sides: usize,
+    /// Inclusive top left and bottom right `(row, col)` corners.
+    pub bounds: ((usize, usize), (usize, usize)),
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+/// Regions of a grid along with the id of the region each cell belongs to.
+#[derive(Debug, Clone)]
+pub struct Regions {
+    labels: Grid<usize>,
+    regions: Vec<Region>,
+}
+
+impl Regions {
+    pub fn label(&self, row: usize, col: usize) -> usize {
+        *self.labels.get(row, col)
+    }
+
+    pub fn get(&self, id: usize) -> &Region {
+        &self.regions[id]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Region> {
+        self.regions.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+}
+
+const UNLABELED: usize = usize::MAX;
+
+impl<T> Grid<T> {
+    /// Group cells into regions, joining cardinal neighbours for which
+    /// `same_region` holds. Region ids follow row-major order of their first
+    /// cell.
+    pub fn regions(&self, same_region: impl Fn(&T, &T) -> bool) -> Regions {
+        let mut labels = self.map(|_| UNLABELED);
+        let mut groups = Vec::new();
+
+        for start in self.positions() {
+            if *labels.get(start.row(), start.col()) != UNLABELED {
+                continue;
+            }
+
+            let id = groups.len();
+            *labels.get_mut(start.row(), start.col()) = id;
+
+            let mut cells = Vec::new();
+            let mut to_visit = vec![start];
+            while let Some(pos) = to_visit.pop() {
+                cells.push(pos.into());
+
+                for n in pos.get_neighbors(&CARDINAL_DIRECTIONS) {
+                    let label = labels.get_mut(n.row(), n.col());
+                    if *label == UNLABELED && same_region(pos.value(), n.value()) {
+                        *label = id;
+                        to_visit.push(n);
+                    }
+                }
+            }
+
+            cells.sort();
+            groups.push(cells);
+        }
+
+        let regions = groups
+            .into_iter()
+            .enumerate()
+            .map(|(id, cells)| Self::measure(&labels, id, cells))
+            .collect();
+
+        Regions { labels, regions }
+    }
+
+    fn measure(labels: &Grid<usize>, id: usize, cells: Vec<(usize, usize)>) -> Region {
+        let inside = |(r, c): (usize, usize), (dr, dc): (isize, isize)| {
+            labels
+                .position(r as isize + dr, c as isize + dc)
+                .is_some_and(|p| *p.value() == id)
+        };
+
+        let mut perimeter = 0;
+        let mut sides = 0;
+        let mut min = cells[0];
+        let mut max = cells[0];
+
+        for &cell in cells.iter() {
+            min = (min.0.min(cell.0), min.1.min(cell.1));
+            max = (max.0.max(cell.0), max.1.max(cell.1));
+
+            for dir in CARDINAL_DIRECTIONS.iter() {
+                let a = get_direction_delta(*dir);
+                let b = get_direction_delta(dir.turn_right());
+
+                if !inside(cell, a) {
+                    perimeter += 1;
+                }
+
+                // Outer corner where both edges are exposed, or an inner
+                // corner where only the diagonal is.
+                let (in_a, in_b) = (inside(cell, a), inside(cell, b));
+                if (!in_a && !in_b) || (in_a && in_b && !inside(cell, (a.0 + b.0, a.1 + b.1))) {
+                    sides += 1;
+                }
+            }
+        }
+
+        Region {
+            id,
+            cells,
+            perimeter,
+            sides,
+            bounds: (min, max),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regions() {
+        let grid = Grid::<char>::parse("AAAA\nBBCD\nBBCC\nEEEC").unwrap();
+        let regions = grid.regions(|a, b| a == b);
+
+        assert_eq!(5, regions.len());
+        assert_eq!(regions.label(1, 0), regions.label(2, 1));
+        assert_ne!(regions.label(1, 2), regions.label(1, 3));
+
+        let a = regions.get(regions.label(0, 0));
+        assert_eq!((4, 10, 4), (a.area(), a.perimeter, a.sides));
+        assert_eq!(((0, 0), (0, 3)), a.bounds);
+
+        let c = regions.get(regions.label(1, 2));
+        assert_eq!((4, 10, 8), (c.area(), c.perimeter, c.sides));
+        assert_eq!(((1, 2), (3, 3)), c.bounds);
+    }
+
+    #[test]
+    fn test_enclosed_region_sides() {
+        let grid = Grid::<char>::parse("AAA\nABA\nAAA").unwrap();
+        let regions = grid.regions(|a, b| a == b);
+
+        let outer = regions.get(regions.label(0, 0));
+        assert_eq!((8, 16, 8), (outer.area(), outer.perimeter, outer.sides));
+    }
+}
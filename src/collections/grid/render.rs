@@ -0,0 +1,192 @@
+use std::{collections::HashSet, fmt::Display, marker::PhantomData};
+
+use crate::collections::point::Point;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    fn ansi_code(&self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+        }
+    }
+}
+
+/// A position to draw over: a `(row, col)` grid cell, or a `Point` in the
+/// coordinates of a sparse grid.
+pub trait Location {
+    fn point(self) -> Point;
+}
+
+impl Location for (usize, usize) {
+    fn point(self) -> Point {
+        Point::from_grid(self)
+    }
+}
+
+impl Location for Point {
+    fn point(self) -> Point {
+        self
+    }
+}
+
+struct Overlay {
+    positions: HashSet<Point>,
+    symbol: Option<char>,
+    color: Color,
+}
+
+/// Text rendering of a grid with highlighted positions drawn on top. Later
+/// overlays take precedence over earlier ones. Colors are only emitted when
+/// enabled with `ansi`.
+pub struct Render<'a, T> {
+    rows: usize,
+    cols: usize,
+    origin: Point,
+    cell: Box<dyn Fn(Point) -> String + 'a>,
+    overlays: Vec<Overlay>,
+    ansi: bool,
+    grid: PhantomData<&'a T>,
+}
+
+impl<'a, T> Render<'a, T> {
+    /// Render `rows` by `cols` cells starting at `origin`, with the text of
+    /// each cell given by its point.
+    pub(crate) fn new(
+        rows: usize,
+        cols: usize,
+        origin: Point,
+        cell: impl Fn(Point) -> String + 'a,
+    ) -> Self {
+        Self {
+            rows,
+            cols,
+            origin,
+            cell: Box::new(cell),
+            overlays: Vec::new(),
+            ansi: false,
+            grid: PhantomData,
+        }
+    }
+
+    /// Highlight positions, keeping the underlying cell text.
+    pub fn highlight(
+        self,
+        positions: impl IntoIterator<Item = impl Location>,
+        color: Color,
+    ) -> Self {
+        self.overlay(positions, None, color)
+    }
+
+    /// Draw `symbol` over positions.
+    pub fn mark(
+        self,
+        positions: impl IntoIterator<Item = impl Location>,
+        symbol: char,
+        color: Color,
+    ) -> Self {
+        self.overlay(positions, Some(symbol), color)
+    }
+
+    pub fn ansi(mut self, enabled: bool) -> Self {
+        self.ansi = enabled;
+        self
+    }
+
+    fn overlay(
+        mut self,
+        positions: impl IntoIterator<Item = impl Location>,
+        symbol: Option<char>,
+        color: Color,
+    ) -> Self {
+        self.overlays.push(Overlay {
+            positions: positions.into_iter().map(Location::point).collect(),
+            symbol,
+            color,
+        });
+        self
+    }
+}
+
+impl<T> Display for Render<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for r in 0..self.rows {
+            if r > 0 {
+                writeln!(f)?;
+            }
+
+            for c in 0..self.cols {
+                let point = self.origin + Point::from_grid((r, c));
+                let overlay = self
+                    .overlays
+                    .iter()
+                    .rev()
+                    .find(|o| o.positions.contains(&point));
+
+                let text = match overlay.and_then(|o| o.symbol) {
+                    Some(symbol) => symbol.to_string(),
+                    None => (self.cell)(point),
+                };
+
+                match overlay {
+                    Some(o) if self.ansi => {
+                        write!(f, "\x1b[{}m{}\x1b[0m", o.color.ansi_code(), text)?
+                    }
+                    _ => write!(f, "{}", text)?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::grid::Grid;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("#..\n.#.\n..#").unwrap()
+    }
+
+    #[test]
+    fn test_render_marks() {
+        let grid = grid();
+        let render =
+            grid.render()
+                .mark([(0, 1), (0, 2)], 'O', Color::Red)
+                .mark([(0, 2)], 'X', Color::Blue);
+
+        assert_eq!("#OX\n.#.\n..#", render.to_string());
+    }
+
+    #[test]
+    fn test_render_with() {
+        let grid = grid();
+        let render = grid.render_with(|c| if *c == '#' { "+" } else { " " }.to_string());
+
+        assert_eq!("+  \n + \n  +", render.to_string());
+    }
+
+    #[test]
+    fn test_render_ansi() {
+        let grid = grid();
+        let render = grid.render().highlight([(1, 1)], Color::Green).ansi(true);
+
+        assert_eq!("#..\n.\x1b[32m#\x1b[0m.\n..#", render.to_string());
+    }
+}
@@ -0,0 +1,218 @@
+use super::Grid;
+
+/// A rectangle of cells starting at `(row, col)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub row: usize,
+    pub col: usize,
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl Rect {
+    pub fn new(row: usize, col: usize, rows: usize, cols: usize) -> Self {
+        Self {
+            row,
+            col,
+            rows,
+            cols,
+        }
+    }
+}
+
+/// A borrowed rectangular part of a grid. Coordinates are relative to the
+/// view's top left corner.
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    rect: Rect,
+}
+
+impl<T> Clone for View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for View<'_, T> {}
+
+impl<'a, T> View<'a, T> {
+    pub fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rect.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.rect.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> &'a T {
+        &self.row(row)[col]
+    }
+
+    pub fn row(&self, row: usize) -> &'a [T] {
+        assert!(row < self.rect.rows, "Row {} outside view", row);
+        let start = self.rect.col;
+        &self.grid.row(self.rect.row + row)[start..start + self.rect.cols]
+    }
+
+    pub fn enumerate(&self) -> impl Iterator<Item = (usize, usize, &'a T)> + 'a {
+        let view = *self;
+        (0..view.rows())
+            .flat_map(move |r| view.row(r).iter().enumerate().map(move |(c, v)| (r, c, v)))
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        (0..self.rows())
+            .map(|r| self.row(r).iter().cloned())
+            .collect()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Borrow the cells inside `rect`, which must lie within the grid.
+    pub fn view(&self, rect: Rect) -> View<'_, T> {
+        assert!(
+            rect.row + rect.rows <= self.rows() && rect.col + rect.cols <= self.cols(),
+            "{:?} outside {}x{} grid",
+            rect,
+            self.rows(),
+            self.cols()
+        );
+        View { grid: self, rect }
+    }
+
+    /// Every `rows` by `cols` view, in row-major order of their top left
+    /// corner.
+    pub fn windows(&self, rows: usize, cols: usize) -> impl Iterator<Item = View<'_, T>> {
+        let row_count = (self.rows() + 1).saturating_sub(rows);
+        let col_count = (self.cols() + 1).saturating_sub(cols);
+
+        (0..row_count)
+            .flat_map(move |r| (0..col_count).map(move |c| self.view(Rect::new(r, c, rows, cols))))
+    }
+
+    pub fn crop(&self, rect: Rect) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.view(rect).to_grid()
+    }
+
+    fn rebuild(
+        &self,
+        rows: usize,
+        cols: usize,
+        cell: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Grid<T>
+    where
+        T: Clone,
+    {
+        (0..rows)
+            .map(|r| {
+                let cell = &cell;
+                (0..cols).map(move |c| {
+                    let (sr, sc) = cell(r, c);
+                    self.get(sr, sc).clone()
+                })
+            })
+            .collect()
+    }
+
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rebuild(self.cols(), self.rows(), |r, c| (c, r))
+    }
+
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let rows = self.rows();
+        self.rebuild(self.cols(), rows, |r, c| (rows - 1 - c, r))
+    }
+
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cols = self.cols();
+        self.rebuild(cols, self.rows(), |r, c| (c, cols - 1 - r))
+    }
+
+    /// Mirror left to right.
+    pub fn flip_horizontal(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cols = self.cols();
+        self.rebuild(self.rows(), cols, |r, c| (r, cols - 1 - c))
+    }
+
+    /// Mirror top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let rows = self.rows();
+        self.rebuild(rows, self.cols(), |r, c| (rows - 1 - r, c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[fixture]
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef").unwrap()
+    }
+
+    #[rstest]
+    fn test_transpose(grid: Grid<char>) {
+        assert_eq!("ad\nbe\ncf", grid.transpose().to_string());
+    }
+
+    #[rstest]
+    fn test_rotate(grid: Grid<char>) {
+        assert_eq!("da\neb\nfc", grid.rotate_cw().to_string());
+        assert_eq!("cf\nbe\nad", grid.rotate_ccw().to_string());
+        assert_eq!(grid.to_string(), grid.rotate_cw().rotate_ccw().to_string());
+    }
+
+    #[rstest]
+    fn test_flip(grid: Grid<char>) {
+        assert_eq!("cba\nfed", grid.flip_horizontal().to_string());
+        assert_eq!("def\nabc", grid.flip_vertical().to_string());
+    }
+
+    #[rstest]
+    fn test_crop(grid: Grid<char>) {
+        assert_eq!("bc\nef", grid.crop(Rect::new(0, 1, 2, 2)).to_string());
+    }
+
+    #[rstest]
+    fn test_windows(grid: Grid<char>) {
+        let windows: Vec<_> = grid.windows(2, 2).collect();
+
+        assert_eq!(2, windows.len());
+        assert_eq!(&['b', 'c'], windows[1].row(0));
+        assert_eq!(&'f', windows[1].get(1, 1));
+        assert_eq!(Rect::new(0, 1, 2, 2), windows[1].rect());
+        assert_eq!(0, grid.windows(3, 1).count());
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn test_view_outside_grid(grid: Grid<char>) {
+        grid.view(Rect::new(1, 1, 2, 2));
+    }
+}
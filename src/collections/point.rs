@@ -0,0 +1,145 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use super::grid::{get_direction_delta, Direction, Grid, Position};
+
+/// A signed 2D coordinate or offset. `x` grows to the east and `y` to the
+/// south, so on a grid `x` is the column and `y` the row.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ZERO: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Wrap into `0..bounds.x` by `0..bounds.y`, as on a torus.
+    pub fn rem_euclid(&self, bounds: Point) -> Point {
+        Point::new(self.x.rem_euclid(bounds.x), self.y.rem_euclid(bounds.y))
+    }
+
+    /// Grid `(row, col)` for this point, if it is not negative.
+    pub fn to_grid(&self) -> Option<(usize, usize)> {
+        Some((self.y.try_into().ok()?, self.x.try_into().ok()?))
+    }
+
+    pub fn from_grid((row, col): (usize, usize)) -> Self {
+        Point::new(col as i64, row as i64)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Point {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/// Unit step in a direction.
+impl From<Direction> for Point {
+    fn from(dir: Direction) -> Self {
+        let (dr, dc) = get_direction_delta(dir);
+        Point::new(dc as i64, dr as i64)
+    }
+}
+
+impl<T> From<Position<'_, T>> for Point {
+    fn from(pos: Position<'_, T>) -> Self {
+        Point::from_grid(pos.into())
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn position_at(&self, point: Point) -> Option<Position<'_, T>> {
+        self.position(point.y, point.x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(2, 4);
+        let b = Point::new(-1, 3);
+
+        assert_eq!(Point::new(1, 7), a + b);
+        assert_eq!(Point::new(3, 1), a - b);
+        assert_eq!(Point::new(-3, 9), b * 3);
+        assert_eq!(Point::new(-2, -4), -a);
+        assert_eq!(4, a.manhattan(&b));
+    }
+
+    #[test]
+    fn test_rem_euclid() {
+        let bounds = Point::new(11, 7);
+        assert_eq!(Point::new(1, 3), Point::new(-10, 10).rem_euclid(bounds));
+    }
+
+    #[test]
+    fn test_grid_conversions() {
+        let grid = Grid::new(2, 3, '.');
+
+        let pos = grid.position_at(Point::new(2, 1)).unwrap();
+        assert_eq!((1, 2), pos.into());
+        assert_eq!(Point::new(2, 1), Point::from(pos));
+        assert!(grid.position_at(Point::new(-1, 0)).is_none());
+        assert_eq!(None, Point::new(-1, 0).to_grid());
+        assert_eq!(Point::new(0, -1), Point::from(Direction::North));
+    }
+}
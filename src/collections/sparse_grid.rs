@@ -0,0 +1,280 @@
+use std::{collections::HashMap, fmt::Display};
+
+use super::{
+    grid::{Direction, Grid, Render},
+    point::Point,
+};
+
+/// A grid that only stores occupied cells, keyed by signed coordinates. It
+/// grows as cells are inserted and keeps track of the bounding box.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    values: HashMap<Point, T>,
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            values: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn extend_bounds(&mut self, point: Point) {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            ),
+            None => (point, point),
+        });
+    }
+
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.extend_bounds(point);
+        self.values.insert(point, value)
+    }
+
+    /// Value of a cell, inserting `default()` if it is empty.
+    pub fn get_or_insert_with(&mut self, point: Point, default: impl FnOnce() -> T) -> &mut T {
+        self.extend_bounds(point);
+        self.values.entry(point).or_insert_with(default)
+    }
+
+    /// Removes a cell, shrinking the bounding box if the cell was on its edge.
+    pub fn remove(&mut self, point: &Point) -> Option<T> {
+        let value = self.values.remove(point)?;
+
+        if let Some((min, max)) = self.bounds {
+            if point.x == min.x || point.y == min.y || point.x == max.x || point.y == max.y {
+                self.bounds = None;
+                let points: Vec<_> = self.values.keys().copied().collect();
+                points.into_iter().for_each(|p| self.extend_bounds(p));
+            }
+        }
+
+        Some(value)
+    }
+
+    pub fn get(&self, point: &Point) -> Option<&T> {
+        self.values.get(point)
+    }
+
+    pub fn get_mut(&mut self, point: &Point) -> Option<&mut T> {
+        self.values.get_mut(point)
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        self.values.contains_key(point)
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Inclusive minimum and maximum corners of the occupied cells.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    /// Occupied cells in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.values.iter().map(|(p, v)| (*p, v))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.values.keys().copied()
+    }
+
+    pub fn get_neighbor(&self, point: Point, dir: Direction) -> Option<(Point, &T)> {
+        let n = point + dir.into();
+        self.get(&n).map(|v| (n, v))
+    }
+
+    /// Occupied neighbours of a cell in the given directions.
+    pub fn get_neighbors<'a>(
+        &'a self,
+        point: Point,
+        dirs: &'a [Direction],
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        dirs.iter()
+            .filter_map(move |d| self.get_neighbor(point, *d))
+    }
+
+    /// Dense copy covering the bounding box, with empty cells set to `fill`.
+    /// Also returns the point that maps to row and column zero. `None` when
+    /// there are no cells.
+    pub fn to_grid(&self, fill: T) -> Option<(Grid<T>, Point)>
+    where
+        T: Clone,
+    {
+        let (min, max) = self.bounds?;
+
+        let fill = &fill;
+        let grid = (min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x).map(move |x| self.get(&Point::new(x, y)).unwrap_or(fill).clone())
+            })
+            .collect();
+
+        Some((grid, min))
+    }
+}
+
+impl<T> SparseGrid<T>
+where
+    T: Display,
+{
+    /// Render the bounding box, showing empty cells as `.`.
+    pub fn render(&self) -> Render<'_, T> {
+        self.render_with(|v| v.map_or(".".to_string(), |v| v.to_string()))
+    }
+}
+
+impl<T> SparseGrid<T> {
+    /// Render the bounding box with a custom representation for cells, which
+    /// are `None` when empty. Overlays take `Point`s.
+    pub fn render_with<'a>(&'a self, cell: impl Fn(Option<&T>) -> String + 'a) -> Render<'a, T> {
+        let (rows, cols, origin) = match self.bounds {
+            Some((min, max)) => (
+                (max.y - min.y + 1) as usize,
+                (max.x - min.x + 1) as usize,
+                min,
+            ),
+            None => (0, 0, Point::ZERO),
+        };
+
+        Render::new(rows, cols, origin, move |p| cell(self.get(&p)))
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (point, value) in iter {
+            grid.insert(point, value);
+        }
+        grid
+    }
+}
+
+impl<T> Grid<T> {
+    /// Sparse copy of the cells matching `keep`.
+    pub fn to_sparse(&self, keep: impl Fn(&T) -> bool) -> SparseGrid<T>
+    where
+        T: Clone,
+    {
+        self.enumerate()
+            .filter(|(_, _, v)| keep(v))
+            .map(|(r, c, v)| (Point::from_grid((r, c)), v.clone()))
+            .collect()
+    }
+}
+
+/// Renders the bounding box, showing empty cells as `.`. An empty grid renders
+/// as nothing.
+impl<T> Display for SparseGrid<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::collections::grid::{Color, CARDINAL_DIRECTIONS};
+
+    fn sparse() -> SparseGrid<char> {
+        [
+            (Point::new(-1, 0), 'a'),
+            (Point::new(1, 2), 'b'),
+            (Point::new(0, 0), 'c'),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = sparse();
+        assert_eq!(Some((Point::new(-1, 0), Point::new(1, 2))), grid.bounds());
+
+        *grid.get_or_insert_with(Point::new(3, -2), || 'd') = 'e';
+        assert_eq!(Some(&'e'), grid.get(&Point::new(3, -2)));
+        assert_eq!(Some((Point::new(-1, -2), Point::new(3, 2))), grid.bounds());
+
+        grid.remove(&Point::new(3, -2));
+        assert_eq!(Some((Point::new(-1, 0), Point::new(1, 2))), grid.bounds());
+
+        grid.remove(&Point::new(1, 2));
+        assert_eq!(Some((Point::new(-1, 0), Point::new(0, 0))), grid.bounds());
+
+        grid.remove(&Point::new(-1, 0));
+        grid.remove(&Point::new(0, 0));
+        assert_eq!(None, grid.bounds());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = sparse();
+        let neighbors: Vec<_> = grid
+            .get_neighbors(Point::new(0, 0), &CARDINAL_DIRECTIONS)
+            .collect();
+
+        assert_eq!(vec![(Point::new(-1, 0), &'a')], neighbors);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("ac.\n...\n..b", sparse().to_string());
+        assert_eq!("", SparseGrid::<char>::new().to_string());
+    }
+
+    #[test]
+    fn test_to_grid() {
+        let (grid, origin) = sparse().to_grid(' ').unwrap();
+        assert_eq!(Point::new(-1, 0), origin);
+        assert_eq!((3, 3), (grid.rows(), grid.cols()));
+        assert_eq!(&'b', grid.get(2, 2));
+
+        assert!(SparseGrid::<char>::new().to_grid(' ').is_none());
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = sparse();
+        let render = grid
+            .render()
+            .mark([Point::new(0, 1)], 'X', Color::Red)
+            .highlight([Point::new(1, 2)], Color::Green);
+        assert_eq!("ac.\n.X.\n..b", render.to_string());
+
+        let render = grid.render_with(|v| v.map_or(" ", |_| "#").to_string());
+        assert_eq!("## \n   \n  #", render.to_string());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let grid = Grid::<char>::parse("#..\n.#.").unwrap();
+        let sparse = grid.to_sparse(|c| *c == '#');
+
+        assert_eq!(2, sparse.len());
+        assert!(sparse.contains(&Point::new(1, 1)));
+        assert_eq!("#.\n.#", sparse.to_string());
+    }
+}
@@ -0,0 +1,486 @@
+//! Emulator for the 3-bit computer from day 17. Programs are lists of 3-bit
+//! words, read as pairs of opcode and operand.
+
+use std::{collections::HashSet, fmt::Display};
+
+mod compile;
+mod quine;
+
+pub use compile::{Expr, LoopBody};
+pub use quine::{check_loop_shape, find_quines};
+
+use crate::{
+    error::{AocError, Result},
+    input::{sections, Pattern},
+};
+
+/// Operand for instructions that take a combo value: a literal from 0 to 3,
+/// or the value of a register.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Combo {
+    Literal(u8),
+    A,
+    B,
+    C,
+}
+
+impl Combo {
+    pub fn decode(operand: u8) -> Result<Self> {
+        match operand {
+            0..=3 => Ok(Combo::Literal(operand)),
+            4 => Ok(Combo::A),
+            5 => Ok(Combo::B),
+            6 => Ok(Combo::C),
+            x => Err(AocError::invalid(format!("Invalid combo operand {}", x))),
+        }
+    }
+
+    pub fn encode(self) -> u8 {
+        match self {
+            Combo::Literal(v) => v,
+            Combo::A => 4,
+            Combo::B => 5,
+            Combo::C => 6,
+        }
+    }
+
+    pub fn value(self, registers: &Registers) -> u64 {
+        match self {
+            Combo::Literal(v) => v as u64,
+            Combo::A => registers.a,
+            Combo::B => registers.b,
+            Combo::C => registers.c,
+        }
+    }
+}
+
+impl Display for Combo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Combo::Literal(v) => write!(f, "{}", v),
+            Combo::A => write!(f, "a"),
+            Combo::B => write!(f, "b"),
+            Combo::C => write!(f, "c"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    /// `a = a >> combo`
+    Adv(Combo),
+    /// `b = b ^ literal`
+    Bxl(u8),
+    /// `b = combo % 8`
+    Bst(Combo),
+    /// Jump to the literal address unless `a` is zero.
+    Jnz(u8),
+    /// `b = b ^ c`. The operand is read but ignored.
+    Bxc(u8),
+    /// Output `combo % 8`.
+    Out(Combo),
+    /// `b = a >> combo`
+    Bdv(Combo),
+    /// `c = a >> combo`
+    Cdv(Combo),
+}
+
+impl Instruction {
+    pub fn decode(opcode: u8, operand: u8) -> Result<Self> {
+        let combo = || Combo::decode(operand);
+        Ok(match opcode {
+            0 => Instruction::Adv(combo()?),
+            1 => Instruction::Bxl(operand),
+            2 => Instruction::Bst(combo()?),
+            3 => Instruction::Jnz(operand),
+            4 => Instruction::Bxc(operand),
+            5 => Instruction::Out(combo()?),
+            6 => Instruction::Bdv(combo()?),
+            7 => Instruction::Cdv(combo()?),
+            x => return Err(AocError::invalid(format!("Invalid opcode {}", x))),
+        })
+    }
+
+    /// Opcode and operand words.
+    pub fn encode(self) -> [u8; 2] {
+        match self {
+            Instruction::Adv(c) => [0, c.encode()],
+            Instruction::Bxl(v) => [1, v],
+            Instruction::Bst(c) => [2, c.encode()],
+            Instruction::Jnz(v) => [3, v],
+            Instruction::Bxc(v) => [4, v],
+            Instruction::Out(c) => [5, c.encode()],
+            Instruction::Bdv(c) => [6, c.encode()],
+            Instruction::Cdv(c) => [7, c.encode()],
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Adv(c) => write!(f, "adv {}", c),
+            Instruction::Bxl(v) => write!(f, "bxl {}", v),
+            Instruction::Bst(c) => write!(f, "bst {}", c),
+            Instruction::Jnz(v) => write!(f, "jnz {}", v),
+            Instruction::Bxc(_) => write!(f, "bxc"),
+            Instruction::Out(c) => write!(f, "out {}", c),
+            Instruction::Bdv(c) => write!(f, "bdv {}", c),
+            Instruction::Cdv(c) => write!(f, "cdv {}", c),
+        }
+    }
+}
+
+/// Prefix an error with the address of the offending instruction.
+fn at(ip: usize, err: AocError) -> AocError {
+    match err {
+        AocError::Invalid(message) => AocError::invalid(format!("Address {}: {}", ip, message)),
+        err => err,
+    }
+}
+
+/// Decode a whole program, assuming every instruction starts at an even
+/// address.
+pub fn decode(program: &[u8]) -> Result<Vec<Instruction>> {
+    program
+        .chunks(2)
+        .enumerate()
+        .map(|(i, words)| match words {
+            [opcode, operand] => Instruction::decode(*opcode, *operand).map_err(|e| at(i * 2, e)),
+            _ => Err(at(i * 2, AocError::invalid("Missing operand"))),
+        })
+        .collect()
+}
+
+/// One instruction per line, prefixed with its address.
+pub fn disassemble(program: &[u8]) -> Result<String> {
+    Ok(decode(program)?
+        .iter()
+        .enumerate()
+        .map(|(i, instruction)| format!("{:>3}: {}", i * 2, instruction))
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Registers {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+}
+
+impl Display for Registers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a={} b={} c={}", self.a, self.b, self.c)
+    }
+}
+
+/// An executed instruction and the registers before it ran.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceEntry {
+    pub ip: usize,
+    pub instruction: Instruction,
+    pub registers: Registers,
+}
+
+impl Display for TraceEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let instruction = self.instruction.to_string();
+        write!(f, "{:>3}: {:<8} {}", self.ip, instruction, self.registers)
+    }
+}
+
+/// Why `run` returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    Halted,
+    /// About to execute the instruction at this address.
+    Breakpoint(usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct Computer {
+    pub registers: Registers,
+    pub ip: usize,
+    program: Vec<u8>,
+    output: Vec<u8>,
+    breakpoints: HashSet<usize>,
+    trace: Option<Vec<TraceEntry>>,
+}
+
+impl Computer {
+    pub fn new(registers: Registers, program: Vec<u8>) -> Result<Self> {
+        if let Some(word) = program.iter().find(|w| **w > 7) {
+            return Err(AocError::invalid(format!("{} is not a 3-bit word", word)));
+        }
+
+        Ok(Self {
+            registers,
+            ip: 0,
+            program,
+            output: Vec::new(),
+            breakpoints: HashSet::new(),
+            trace: None,
+        })
+    }
+
+    /// Parse the register and program listing from the puzzle input.
+    pub fn parse(input: &str) -> Result<Self> {
+        let sections = sections(input);
+        let [registers, program] = sections.as_slice() else {
+            return Err(AocError::invalid(
+                "Expected registers and program separated by a blank line",
+            ));
+        };
+
+        let names = ["A", "B", "C"];
+        if registers.lines().len() != names.len() {
+            return Err(AocError::invalid("Expected registers A, B and C"));
+        }
+
+        let mut values = [0; 3];
+        for ((value, name), line) in values.iter_mut().zip(names).zip(registers.lines()) {
+            *value = Pattern::new(&format!("Register {}: {{}}", name)).extract(line)?[0];
+        }
+
+        let line = program
+            .lines()
+            .first()
+            .ok_or_else(|| AocError::invalid("Missing program"))?;
+        Pattern::new("Program: {}").extract::<String>(line)?;
+        let words = line.numbers::<u8>()?;
+        if let Some(word) = words.iter().find(|w| **w > 7) {
+            return Err(line.error(format!("{} is not a 3-bit word", word)));
+        }
+
+        let [a, b, c] = values;
+        Self::new(Registers { a, b, c }, words)
+    }
+
+    pub fn program(&self) -> &[u8] {
+        &self.program
+    }
+
+    pub fn output(&self) -> &[u8] {
+        &self.output
+    }
+
+    /// Restart the program with the given registers, clearing output and the
+    /// trace but keeping breakpoints.
+    pub fn reset(&mut self, registers: Registers) {
+        self.registers = registers;
+        self.ip = 0;
+        self.output.clear();
+        if let Some(trace) = self.trace.as_mut() {
+            trace.clear();
+        }
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.ip >= self.program.len()
+    }
+
+    /// The instruction at the instruction pointer, if the program has not
+    /// halted.
+    pub fn current(&self) -> Result<Option<Instruction>> {
+        let Some(&opcode) = self.program.get(self.ip) else {
+            return Ok(None);
+        };
+        let operand = *self
+            .program
+            .get(self.ip + 1)
+            .ok_or_else(|| at(self.ip, AocError::invalid("Missing operand")))?;
+
+        Instruction::decode(opcode, operand)
+            .map(Some)
+            .map_err(|e| at(self.ip, e))
+    }
+
+    pub fn add_breakpoint(&mut self, ip: usize) {
+        self.breakpoints.insert(ip);
+    }
+
+    pub fn remove_breakpoint(&mut self, ip: usize) {
+        self.breakpoints.remove(&ip);
+    }
+
+    /// Start recording every executed instruction.
+    pub fn enable_trace(&mut self) {
+        self.trace.get_or_insert_with(Vec::new);
+    }
+
+    pub fn trace(&self) -> &[TraceEntry] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
+    /// Execute a single instruction. Returns the instruction, or `None` if the
+    /// program has already halted.
+    pub fn step(&mut self) -> Result<Option<Instruction>> {
+        let Some(instruction) = self.current()? else {
+            return Ok(None);
+        };
+
+        if let Some(trace) = self.trace.as_mut() {
+            trace.push(TraceEntry {
+                ip: self.ip,
+                instruction,
+                registers: self.registers,
+            });
+        }
+
+        let regs = &mut self.registers;
+        // Shifting by 64 or more clears the value rather than overflowing.
+        let shifted = |a: u64, c: Combo, regs: &Registers| {
+            a.checked_shr(c.value(regs).try_into().unwrap_or(u32::MAX))
+                .unwrap_or(0)
+        };
+
+        self.ip += 2;
+        match instruction {
+            Instruction::Adv(c) => regs.a = shifted(regs.a, c, regs),
+            Instruction::Bxl(v) => regs.b ^= v as u64,
+            Instruction::Bst(c) => regs.b = c.value(regs) % 8,
+            Instruction::Jnz(v) => {
+                if regs.a != 0 {
+                    self.ip = v as usize;
+                }
+            }
+            Instruction::Bxc(_) => regs.b ^= regs.c,
+            Instruction::Out(c) => self.output.push((c.value(regs) % 8) as u8),
+            Instruction::Bdv(c) => regs.b = shifted(regs.a, c, regs),
+            Instruction::Cdv(c) => regs.c = shifted(regs.a, c, regs),
+        }
+
+        Ok(Some(instruction))
+    }
+
+    /// Run until the program halts or reaches a breakpoint. Always executes
+    /// at least one instruction, so calling it again resumes from a
+    /// breakpoint.
+    pub fn run(&mut self) -> Result<Stop> {
+        if self.step()?.is_none() {
+            return Ok(Stop::Halted);
+        }
+
+        loop {
+            if self.is_halted() {
+                return Ok(Stop::Halted);
+            }
+            if self.breakpoints.contains(&self.ip) {
+                return Ok(Stop::Breakpoint(self.ip));
+            }
+            self.step()?;
+        }
+    }
+
+    /// Run the program to completion ignoring breakpoints, and return its
+    /// output.
+    pub fn run_to_end(&mut self) -> Result<&[u8]> {
+        while self.step()?.is_some() {}
+        Ok(self.output())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn computer(a: u64, program: &[u8]) -> Computer {
+        Computer::new(Registers { a, b: 0, c: 0 }, program.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_encode_round_trip() {
+        let program = [2, 4, 1, 1, 7, 5, 4, 7, 0, 3, 5, 5, 3, 0];
+        let encoded: Vec<_> = decode(&program)
+            .unwrap()
+            .iter()
+            .flat_map(|i| i.encode())
+            .collect();
+
+        assert_eq!(program.to_vec(), encoded);
+        assert!(Instruction::decode(0, 7).is_err());
+    }
+
+    #[test]
+    fn test_disassemble() {
+        assert_eq!(
+            "  0: adv 1\n  2: out a\n  4: jnz 0",
+            disassemble(&[0, 1, 5, 4, 3, 0]).unwrap()
+        );
+        assert!(disassemble(&[0, 1, 5]).is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        let computer =
+            Computer::parse("Register A: 729\nRegister B: 2\nRegister C: 9\n\nProgram: 0,1,5,4")
+                .unwrap();
+
+        assert_eq!(Registers { a: 729, b: 2, c: 9 }, computer.registers);
+        assert_eq!(&[0, 1, 5, 4], computer.program());
+
+        let err = Computer::parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,8")
+            .unwrap_err();
+        assert_eq!("line 5: 8 is not a 3-bit word", err.to_string());
+    }
+
+    #[test]
+    fn test_instructions() {
+        let mut c = Computer::new(Registers { a: 0, b: 0, c: 9 }, vec![2, 6]).unwrap();
+        c.run_to_end().unwrap();
+        assert_eq!(1, c.registers.b);
+
+        let mut c = computer(10, &[5, 0, 5, 1, 5, 4]);
+        assert_eq!(&[0, 1, 2], c.run_to_end().unwrap());
+
+        let mut c = computer(2024, &[0, 1, 5, 4, 3, 0]);
+        assert_eq!(&[4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0], c.run_to_end().unwrap());
+        assert_eq!(0, c.registers.a);
+
+        let mut c = Computer::new(Registers { a: 0, b: 29, c: 0 }, vec![1, 7]).unwrap();
+        c.run_to_end().unwrap();
+        assert_eq!(26, c.registers.b);
+
+        let mut c = Computer::new(
+            Registers {
+                a: 0,
+                b: 2024,
+                c: 43690,
+            },
+            vec![4, 0],
+        )
+        .unwrap();
+        c.run_to_end().unwrap();
+        assert_eq!(44354, c.registers.b);
+    }
+
+    #[test]
+    fn test_step_and_breakpoints() {
+        let mut c = computer(16, &[0, 1, 5, 4, 3, 0]);
+        c.enable_trace();
+        c.add_breakpoint(2);
+
+        assert_eq!(Some(Instruction::Adv(Combo::Literal(1))), c.step().unwrap());
+        assert_eq!((2, 8), (c.ip, c.registers.a));
+
+        assert_eq!(Stop::Breakpoint(2), c.run().unwrap());
+        assert_eq!(&[0], c.output());
+
+        c.remove_breakpoint(2);
+        assert_eq!(Stop::Halted, c.run().unwrap());
+        assert_eq!(None, c.step().unwrap());
+
+        assert_eq!(15, c.trace().len());
+        assert_eq!("  2: out a    a=8 b=0 c=0", c.trace()[1].to_string());
+    }
+
+    #[test]
+    fn test_invalid_combo_at_runtime() {
+        let mut c = computer(1, &[5, 7]);
+        assert_eq!(
+            "invalid input: Address 0: Invalid combo operand 7",
+            c.step().unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use std::fmt::Display;
+
+use super::{decode, Combo, Instruction, Registers};
+use crate::error::{AocError, Result};
+
+/// A value computed by a program, in terms of the registers at the start of a
+/// pass through the loop. Built through the constructors below, which fold
+/// constants as they go.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Const(u64),
+    A,
+    B,
+    C,
+    Shr(Box<Expr>, Box<Expr>),
+    Xor(Box<Expr>, Box<Expr>),
+    Mod8(Box<Expr>),
+}
+
+/// Shift that clears the value instead of overflowing, as the interpreter
+/// does.
+fn shr(value: u64, shift: u64) -> u64 {
+    u32::try_from(shift)
+        .ok()
+        .and_then(|s| value.checked_shr(s))
+        .unwrap_or(0)
+}
+
+impl Expr {
+    pub fn shift(value: Expr, shift: Expr) -> Expr {
+        match (value, shift) {
+            (Expr::Const(v), Expr::Const(s)) => Expr::Const(shr(v, s)),
+            (Expr::Const(0), _) => Expr::Const(0),
+            (value, Expr::Const(0)) => value,
+            (value, shift) => Expr::Shr(Box::new(value), Box::new(shift)),
+        }
+    }
+
+    pub fn xor(lhs: Expr, rhs: Expr) -> Expr {
+        match (lhs, rhs) {
+            (Expr::Const(l), Expr::Const(r)) => Expr::Const(l ^ r),
+            (value, Expr::Const(0)) | (Expr::Const(0), value) => value,
+            (lhs, rhs) if lhs == rhs => Expr::Const(0),
+            (lhs, rhs) => Expr::Xor(Box::new(lhs), Box::new(rhs)),
+        }
+    }
+
+    pub fn mod8(value: Expr) -> Expr {
+        match value {
+            Expr::Const(v) => Expr::Const(v % 8),
+            Expr::Mod8(inner) => Expr::Mod8(inner),
+            value => Expr::Mod8(Box::new(value)),
+        }
+    }
+
+    fn combo(combo: Combo, registers: &[Expr; 3]) -> Expr {
+        match combo {
+            Combo::Literal(v) => Expr::Const(v as u64),
+            Combo::A => registers[0].clone(),
+            Combo::B => registers[1].clone(),
+            Combo::C => registers[2].clone(),
+        }
+    }
+
+    /// Evaluate by walking the tree.
+    pub fn eval(&self, registers: &Registers) -> u64 {
+        match self {
+            Expr::Const(v) => *v,
+            Expr::A => registers.a,
+            Expr::B => registers.b,
+            Expr::C => registers.c,
+            Expr::Shr(v, s) => shr(v.eval(registers), s.eval(registers)),
+            Expr::Xor(l, r) => l.eval(registers) ^ r.eval(registers),
+            Expr::Mod8(v) => v.eval(registers) % 8,
+        }
+    }
+
+    /// Turn the tree into nested closures, so evaluating it no longer
+    /// matches on the tree.
+    pub fn compile(&self) -> Box<dyn Fn(&Registers) -> u64> {
+        match self {
+            Expr::Const(v) => {
+                let v = *v;
+                Box::new(move |_| v)
+            }
+            Expr::A => Box::new(|r| r.a),
+            Expr::B => Box::new(|r| r.b),
+            Expr::C => Box::new(|r| r.c),
+            Expr::Shr(v, s) => match **s {
+                Expr::Const(s) => {
+                    let v = v.compile();
+                    Box::new(move |r| shr(v(r), s))
+                }
+                _ => {
+                    let (v, s) = (v.compile(), s.compile());
+                    Box::new(move |r| shr(v(r), s(r)))
+                }
+            },
+            Expr::Xor(l, r) => match **r {
+                Expr::Const(c) => {
+                    let l = l.compile();
+                    Box::new(move |regs| l(regs) ^ c)
+                }
+                _ => {
+                    let (l, r) = (l.compile(), r.compile());
+                    Box::new(move |regs| l(regs) ^ r(regs))
+                }
+            },
+            Expr::Mod8(v) => {
+                let v = v.compile();
+                Box::new(move |r| v(r) & 7)
+            }
+        }
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expr::Const(v) => write!(f, "{}", v),
+            Expr::A => write!(f, "a"),
+            Expr::B => write!(f, "b"),
+            Expr::C => write!(f, "c"),
+            Expr::Shr(v, s) => write!(f, "({} >> {})", v, s),
+            Expr::Xor(l, r) => write!(f, "({} ^ {})", l, r),
+            Expr::Mod8(v) => write!(f, "({} % 8)", v),
+        }
+    }
+}
+
+/// One pass through the body of a program that loops with a single trailing
+/// `jnz 0`, as expressions over the registers at the start of the pass.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoopBody {
+    pub outputs: Vec<Expr>,
+    pub a: Expr,
+    pub b: Expr,
+    pub c: Expr,
+}
+
+impl LoopBody {
+    pub fn compile(program: &[u8]) -> Result<Self> {
+        let instructions = decode(program)?;
+        let Some((Instruction::Jnz(0), body)) = instructions.split_last() else {
+            return Err(AocError::invalid(
+                "Only programs ending in `jnz 0` can be compiled",
+            ));
+        };
+
+        let mut registers = [Expr::A, Expr::B, Expr::C];
+        let mut outputs = Vec::new();
+
+        for instruction in body {
+            let [a, b, c] = &registers;
+            let combo = |c: &Combo| Expr::combo(*c, &registers);
+            let (a, b, c) = (a.clone(), b.clone(), c.clone());
+
+            match instruction {
+                Instruction::Adv(x) => registers[0] = Expr::shift(a, combo(x)),
+                Instruction::Bxl(v) => registers[1] = Expr::xor(b, Expr::Const(*v as u64)),
+                Instruction::Bst(x) => registers[1] = Expr::mod8(combo(x)),
+                Instruction::Jnz(_) => {
+                    return Err(AocError::invalid(
+                        "Jumps inside the loop body can't be compiled",
+                    ))
+                }
+                Instruction::Bxc(_) => registers[1] = Expr::xor(b, c),
+                Instruction::Out(x) => outputs.push(Expr::mod8(combo(x))),
+                Instruction::Bdv(x) => registers[1] = Expr::shift(a, combo(x)),
+                Instruction::Cdv(x) => registers[2] = Expr::shift(a, combo(x)),
+            }
+        }
+
+        let [a, b, c] = registers;
+        Ok(Self { outputs, a, b, c })
+    }
+
+    /// Run the pass, returning its output and the registers after it.
+    pub fn eval(&self, registers: &Registers) -> (Vec<u8>, Registers) {
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|e| e.eval(registers) as u8)
+            .collect();
+        let next = Registers {
+            a: self.a.eval(registers),
+            b: self.b.eval(registers),
+            c: self.c.eval(registers),
+        };
+
+        (outputs, next)
+    }
+
+    /// Run the whole program, repeating the pass until `a` is zero.
+    pub fn run(&self, mut registers: Registers) -> Vec<u8> {
+        let mut output = Vec::new();
+        loop {
+            let (out, next) = self.eval(&registers);
+            output.extend(out);
+            registers = next;
+            if registers.a == 0 {
+                return output;
+            }
+        }
+    }
+}
+
+impl Display for LoopBody {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for out in self.outputs.iter() {
+            writeln!(f, "out {}", out)?;
+        }
+        write!(f, "a = {}\nb = {}\nc = {}", self.a, self.b, self.c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::computer::Computer;
+
+    const PROGRAMS: [&[u8]; 3] = [
+        &[0, 3, 5, 4, 3, 0],
+        &[2, 4, 1, 1, 7, 5, 4, 7, 0, 3, 5, 5, 3, 0],
+        &[2, 4, 1, 5, 7, 5, 1, 6, 0, 3, 4, 0, 5, 5, 3, 0],
+    ];
+
+    fn interpret(program: &[u8], registers: Registers) -> Vec<u8> {
+        let mut computer = Computer::new(registers, program.to_vec()).unwrap();
+        computer.run_to_end().unwrap().to_vec()
+    }
+
+    #[test]
+    fn test_folding() {
+        let body = LoopBody::compile(&[2, 4, 1, 1, 7, 5, 1, 0, 0, 3, 5, 5, 3, 0]).unwrap();
+
+        assert_eq!(
+            "out (((a % 8) ^ 1) % 8)\na = (a >> 3)\nb = ((a % 8) ^ 1)\nc = (a >> ((a % 8) ^ 1))",
+            body.to_string()
+        );
+        assert_eq!(Expr::Const(0), Expr::xor(Expr::A, Expr::A));
+        assert_eq!(Expr::Const(1), Expr::shift(Expr::Const(8), Expr::Const(3)));
+    }
+
+    #[test]
+    fn test_matches_interpreter() {
+        for program in PROGRAMS {
+            let body = LoopBody::compile(program).unwrap();
+            let first = body.outputs[0].compile();
+
+            for a in (1..5000).chain([117440, 0o3_5170_1243_6354, u64::MAX]) {
+                let registers = Registers { a, b: 0, c: 0 };
+                let expected = interpret(program, registers);
+
+                assert_eq!(expected, body.run(registers), "{:?} a={}", program, a);
+                assert_eq!(expected[0] as u64, first(&registers));
+                assert_eq!(expected[0] as u64, body.outputs[0].eval(&registers));
+            }
+        }
+    }
+
+    #[test]
+    fn test_rejects_other_jumps() {
+        assert!(LoopBody::compile(&[5, 4, 3, 2]).is_err());
+        assert!(LoopBody::compile(&[3, 4, 5, 4, 3, 0]).is_err());
+    }
+}
@@ -0,0 +1,233 @@
+use tracing::{debug, info, warn};
+
+use super::{decode, Combo, Computer, Instruction, LoopBody, Registers};
+use crate::error::{AocError, Result};
+
+/// Steps a single candidate may take in the brute force search before it is
+/// assumed to loop forever.
+const MAX_STEPS: usize = 100_000;
+
+/// Registers read and written by an instruction, as combo register operands.
+fn accesses(instruction: Instruction) -> (Vec<Combo>, Option<Combo>) {
+    let read = |c: Combo| match c {
+        Combo::Literal(_) => vec![],
+        register => vec![register],
+    };
+
+    match instruction {
+        Instruction::Adv(c) => ([vec![Combo::A], read(c)].concat(), Some(Combo::A)),
+        Instruction::Bxl(_) => (vec![Combo::B], Some(Combo::B)),
+        Instruction::Bst(c) => (read(c), Some(Combo::B)),
+        Instruction::Jnz(_) => (vec![Combo::A], None),
+        Instruction::Bxc(_) => (vec![Combo::B, Combo::C], Some(Combo::B)),
+        Instruction::Out(c) => (read(c), None),
+        Instruction::Bdv(c) => ([vec![Combo::A], read(c)].concat(), Some(Combo::B)),
+        Instruction::Cdv(c) => ([vec![Combo::A], read(c)].concat(), Some(Combo::C)),
+    }
+}
+
+/// Check that the program is a single loop which outputs one value per
+/// iteration, shifts `a` right by 3 bits and doesn't carry `b` or `c` over
+/// from the previous iteration. Such programs can be solved 3 bits at a time.
+pub fn check_loop_shape(program: &[u8]) -> Result<()> {
+    let instructions = decode(program)?;
+
+    let Some((last, body)) = instructions.split_last() else {
+        return Err(AocError::invalid("Program is empty"));
+    };
+    if *last != Instruction::Jnz(0) {
+        return Err(AocError::invalid(format!(
+            "Program ends with `{}` rather than `jnz 0`",
+            last
+        )));
+    }
+
+    let count = |f: fn(&Instruction) -> bool| body.iter().filter(|i| f(i)).count();
+    if count(|i| matches!(i, Instruction::Jnz(_))) > 0 {
+        return Err(AocError::invalid(
+            "Program jumps before the end of the loop",
+        ));
+    }
+    if count(|i| matches!(i, Instruction::Out(_))) != 1 {
+        return Err(AocError::invalid("Loop must output exactly one value"));
+    }
+    if count(|i| matches!(i, Instruction::Adv(_))) != 1
+        || count(|i| *i == Instruction::Adv(Combo::Literal(3))) != 1
+    {
+        return Err(AocError::invalid(
+            "Loop must shift `a` with a single `adv 3`",
+        ));
+    }
+
+    let mut written = Vec::new();
+    for &instruction in body {
+        let (reads, write) = accesses(instruction);
+        if let Some(r) = reads
+            .into_iter()
+            .find(|r| *r != Combo::A && !written.contains(r))
+        {
+            return Err(AocError::invalid(format!(
+                "`{}` reads `{}` before the loop sets it",
+                instruction, r
+            )));
+        }
+        written.extend(write);
+    }
+
+    Ok(())
+}
+
+/// Build `a` from the most significant 3 bits down. The last iteration sees
+/// only the top 3 bits, the one before it the top 6 and so on, so each output
+/// fixes 3 more bits.
+fn solve_loop(program: &[u8]) -> Result<Vec<u64>> {
+    let body = LoopBody::compile(program)?;
+    debug!("Loop body:\n{}", body);
+    let output = body.outputs[0].compile();
+
+    let mut candidates = vec![0];
+    for (i, target) in program.iter().enumerate().rev() {
+        let mut next = Vec::new();
+        for prefix in candidates {
+            for a in (prefix << 3)..(prefix << 3) + 8 {
+                if a != 0 && output(&Registers { a, b: 0, c: 0 }) == *target as u64 {
+                    next.push(a);
+                }
+            }
+        }
+
+        debug!("{} candidates for output {}", next.len(), i);
+        candidates = next;
+    }
+
+    Ok(candidates)
+}
+
+/// Whether the program outputs itself when started from `a`, giving up once
+/// the output diverges or it takes too long.
+fn is_quine(computer: &mut Computer, a: u64) -> Result<bool> {
+    computer.reset(Registers { a, b: 0, c: 0 });
+    let len = computer.program().len();
+
+    for _ in 0..MAX_STEPS {
+        match computer.step()? {
+            None => return Ok(computer.output() == computer.program()),
+            Some(Instruction::Out(_)) => {
+                let output = computer.output();
+                if output.len() > len || output != &computer.program()[..output.len()] {
+                    return Ok(false);
+                }
+            }
+            Some(_) => {}
+        }
+    }
+
+    Ok(false)
+}
+
+/// Every initial value of `a` for which the program outputs a copy of
+/// itself, in increasing order, with `b` and `c` starting at zero.
+///
+/// Programs that pass `check_loop_shape` are solved exactly. Any other
+/// program is searched by brute force over `0..limit`.
+pub fn find_quines(computer: &Computer, limit: u64) -> Result<Vec<u64>> {
+    let mut computer = computer.clone();
+
+    let candidates = match check_loop_shape(computer.program()) {
+        Ok(()) => {
+            info!("Solving loop 3 bits at a time");
+            solve_loop(computer.program())?
+        }
+        Err(err) => {
+            let reason = match err {
+                AocError::Invalid(message) => message,
+                err => return Err(err),
+            };
+            warn!("{}. Falling back to brute force below {}", reason, limit);
+            let mut found = Vec::new();
+            for a in 0..limit {
+                if is_quine(&mut computer, a)? {
+                    found.push(a);
+                }
+            }
+
+            if found.is_empty() {
+                return Err(AocError::no_solution(format!(
+                    "Program is not a simple loop ({}) and no quine exists below {}",
+                    reason, limit
+                )));
+            }
+            found
+        }
+    };
+
+    let mut quines = Vec::new();
+    for a in candidates {
+        if is_quine(&mut computer, a)? {
+            quines.push(a);
+        }
+    }
+    quines.sort();
+
+    if quines.is_empty() {
+        return Err(AocError::no_solution(
+            "No value of a makes the program output itself",
+        ));
+    }
+
+    Ok(quines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn computer(program: &[u8]) -> Computer {
+        Computer::new(Registers::default(), program.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_check_loop_shape() {
+        assert!(check_loop_shape(&[0, 3, 5, 4, 3, 0]).is_ok());
+        assert!(check_loop_shape(&[2, 4, 1, 1, 7, 5, 4, 7, 0, 3, 5, 5, 3, 0]).is_ok());
+
+        let err = |program: &[u8]| check_loop_shape(program).unwrap_err().to_string();
+        assert_eq!(
+            "invalid input: Program ends with `out a` rather than `jnz 0`",
+            err(&[0, 3, 5, 4])
+        );
+        assert_eq!(
+            "invalid input: Loop must shift `a` with a single `adv 3`",
+            err(&[0, 2, 5, 4, 3, 0])
+        );
+        assert_eq!(
+            "invalid input: `out b` reads `b` before the loop sets it",
+            err(&[0, 3, 5, 5, 3, 0])
+        );
+    }
+
+    #[test]
+    fn test_find_quines_in_loop() {
+        let quines = find_quines(&computer(&[0, 3, 5, 4, 3, 0]), 0).unwrap();
+        assert_eq!((117440..117448).collect::<Vec<_>>(), quines);
+    }
+
+    #[test]
+    fn test_find_quines_by_brute_force() {
+        // The loop jumps back past `bxl 0`, so it doesn't fit the pattern,
+        // but still prints every octal digit of `a`.
+        let program = [1, 0, 5, 4, 0, 3, 3, 2];
+        let quines = find_quines(&computer(&program), 0o23304502).unwrap();
+        assert_eq!(vec![0o23304501], quines);
+    }
+
+    #[test]
+    fn test_no_quine() {
+        let err = find_quines(&computer(&[5, 4, 0, 1, 3, 0]), 1 << 10).unwrap_err();
+        assert_eq!(
+            "no solution: Program is not a simple loop (Loop must shift `a` with a single \
+             `adv 3`) and no quine exists below 1024",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use crate::error::{AocError, Result};
+
+/// Puzzle parameters that differ between the samples and the real input, such
+/// as grid sizes. Solutions fall back to the real input values when a parameter
+/// is not set.
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+    params: HashMap<String, String>,
+}
+
+impl Context {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, key: &str, value: impl Display) {
+        self.params.insert(key.to_string(), value.to_string());
+    }
+
+    pub fn with_param(mut self, key: &str, value: impl Display) -> Self {
+        self.set(key, value);
+        self
+    }
+
+    /// The parameter's value, or `None` when it is not set. Fails if the value
+    /// doesn't parse.
+    pub fn param<T>(&self, key: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+    {
+        self.params
+            .get(key)
+            .map(|v| {
+                v.parse().map_err(|_| {
+                    AocError::invalid(format!("Invalid value for parameter {}: {}", key, v))
+                })
+            })
+            .transpose()
+    }
+}
+
+/// Parse a `key=value` command line parameter.
+pub fn parse_param(s: &str) -> std::result::Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((k, v)) if !k.is_empty() => Ok((k.to_string(), v.to_string())),
+        _ => Err(format!("Expected KEY=VALUE, found {}", s)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_param() {
+        let ctx = Context::new().with_param("width", 11);
+
+        assert_eq!(Some(11), ctx.param::<usize>("width").unwrap());
+        assert_eq!(103, ctx.param("height").unwrap().unwrap_or(103));
+    }
+
+    #[test]
+    fn test_invalid_param() {
+        let ctx = Context::new().with_param("width", "wide");
+        assert_eq!(
+            "invalid input: Invalid value for parameter width: wide",
+            ctx.param::<usize>("width").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_param() {
+        assert_eq!(
+            Ok(("width".to_string(), "11".to_string())),
+            parse_param("width=11")
+        );
+        assert!(parse_param("width").is_err());
+        assert!(parse_param("=11").is_err());
+    }
+}
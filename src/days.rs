@@ -0,0 +1,165 @@
+mod day_01;
+mod day_02;
+mod day_03;
+mod day_04;
+mod day_05;
+mod day_06;
+mod day_07;
+mod day_08;
+mod day_09;
+mod day_10;
+mod day_11;
+mod day_12;
+mod day_13;
+mod day_14;
+mod day_15;
+mod day_16;
+mod day_17;
+mod day_18;
+mod day_19;
+mod day_20;
+mod day_21;
+mod day_22;
+mod day_23;
+mod day_24;
+mod day_25;
+
+use crate::{solution, Solution};
+
+pub struct Day {
+    pub number: u32,
+    pub part1: Solution,
+    pub part2: Option<Solution>,
+}
+
+pub const DAYS: [Day; 25] = [
+    Day {
+        number: 1,
+        part1: solution!(day_01::part1),
+        part2: Some(solution!(day_01::part2)),
+    },
+    Day {
+        number: 2,
+        part1: solution!(day_02::part1),
+        part2: Some(solution!(day_02::part2)),
+    },
+    Day {
+        number: 3,
+        part1: solution!(day_03::part1),
+        part2: Some(solution!(day_03::part2)),
+    },
+    Day {
+        number: 4,
+        part1: solution!(day_04::part1),
+        part2: Some(solution!(day_04::part2)),
+    },
+    Day {
+        number: 5,
+        part1: solution!(day_05::part1),
+        part2: Some(solution!(day_05::part2)),
+    },
+    Day {
+        number: 6,
+        part1: solution!(day_06::part1),
+        part2: Some(solution!(day_06::part2)),
+    },
+    Day {
+        number: 7,
+        part1: solution!(day_07::part1),
+        part2: Some(solution!(day_07::part2)),
+    },
+    Day {
+        number: 8,
+        part1: solution!(day_08::part1),
+        part2: Some(solution!(day_08::part2)),
+    },
+    Day {
+        number: 9,
+        part1: solution!(day_09::part1),
+        part2: Some(solution!(day_09::part2)),
+    },
+    Day {
+        number: 10,
+        part1: solution!(day_10::part1),
+        part2: Some(solution!(day_10::part2)),
+    },
+    Day {
+        number: 11,
+        part1: solution!(day_11::part1),
+        part2: Some(solution!(day_11::part2)),
+    },
+    Day {
+        number: 12,
+        part1: solution!(day_12::part1),
+        part2: Some(solution!(day_12::part2)),
+    },
+    Day {
+        number: 13,
+        part1: solution!(day_13::part1),
+        part2: Some(solution!(day_13::part2)),
+    },
+    Day {
+        number: 14,
+        part1: solution!(day_14::part1),
+        part2: Some(solution!(day_14::part2)),
+    },
+    Day {
+        number: 15,
+        part1: solution!(day_15::part1),
+        part2: Some(solution!(day_15::part2)),
+    },
+    Day {
+        number: 16,
+        part1: solution!(day_16::part1),
+        part2: Some(solution!(day_16::part2)),
+    },
+    Day {
+        number: 17,
+        part1: solution!(day_17::part1),
+        part2: Some(solution!(day_17::part2)),
+    },
+    Day {
+        number: 18,
+        part1: solution!(day_18::part1),
+        part2: Some(solution!(day_18::part2)),
+    },
+    Day {
+        number: 19,
+        part1: solution!(day_19::part1),
+        part2: Some(solution!(day_19::part2)),
+    },
+    Day {
+        number: 20,
+        part1: solution!(day_20::part1),
+        part2: Some(solution!(day_20::part2)),
+    },
+    Day {
+        number: 21,
+        part1: solution!(day_21::part1),
+        part2: Some(solution!(day_21::part2)),
+    },
+    Day {
+        number: 22,
+        part1: solution!(day_22::part1),
+        part2: Some(solution!(day_22::part2)),
+    },
+    Day {
+        number: 23,
+        part1: solution!(day_23::part1),
+        part2: Some(solution!(day_23::part2)),
+    },
+    Day {
+        number: 24,
+        part1: solution!(day_24::part1),
+        part2: Some(solution!(day_24::part2)),
+    },
+    Day {
+        number: 25,
+        part1: solution!(day_25::part1),
+        part2: None,
+    },
+];
+
+pub fn get(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use crate::{input::get_all_numbers, sample};
+
+fn parse(input: &str) -> (Vec<i32>, Vec<i32>) {
+    let mut first = Vec::new();
+    let mut second = Vec::new();
+
+    input.split('\n').for_each(|l| {
+        let nums = get_all_numbers::<i32>(l);
+        first.push(*nums.first().unwrap());
+        second.push(*nums.last().unwrap());
+    });
+
+    (first, second)
+}
+
+pub fn part1(input: &str) -> i32 {
+    let (mut first, mut second) = parse(input);
+
+    first.sort();
+    second.sort();
+
+    let total: i32 = first
+        .into_iter()
+        .zip(second)
+        .map(|(a, b)| (a - b).abs())
+        .sum();
+
+    total
+}
+
+pub fn part2(input: &str) -> i32 {
+    let (first, second) = parse(input);
+
+    let mut counts: HashMap<i32, i32> = HashMap::new();
+
+    second.into_iter().for_each(|k| {
+        *counts.entry(k).or_insert(0) += 1;
+    });
+
+    let total: i32 = first
+        .into_iter()
+        .map(|v| v * counts.get(&v).unwrap_or(&0))
+        .sum();
+
+    total
+}
+
+sample! {
+    r"
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3",
+    part1 = 11,
+    part2 = 31
+}
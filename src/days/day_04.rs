@@ -0,0 +1,69 @@
+use std::iter::successors;
+
+use crate::{
+    collections::grid::{Grid, Position, ALL_DIRECTIONS},
+    error::Result,
+    sample,
+};
+use tracing::debug;
+
+fn children(pos: Position<'_, char>, depth: usize) -> Vec<String> {
+    ALL_DIRECTIONS
+        .iter()
+        .map(|dir| {
+            successors(Some(pos), |p| p.get_neighbor(*dir))
+                .take(depth)
+                .map(|p| *p.value())
+                .collect()
+        })
+        .collect()
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let chars: Grid<char> = Grid::parse(input)?;
+
+    let mut total = 0;
+    for pos in chars.positions() {
+        let words = children(pos, 4);
+        debug!("{:?}", words);
+        total += words.iter().filter(|w| *w == "XMAS").count();
+    }
+
+    Ok(total)
+}
+
+fn is_mas(a: char, b: char) -> bool {
+    matches!((a, b), ('M', 'S') | ('S', 'M'))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let chars: Grid<char> = Grid::parse(input)?;
+
+    let total = chars
+        .windows(3, 3)
+        .filter(|w| {
+            debug!("Checking window at {:?}", w.rect());
+            *w.get(1, 1) == 'A'
+                && is_mas(*w.get(0, 0), *w.get(2, 2))
+                && is_mas(*w.get(2, 0), *w.get(0, 2))
+        })
+        .count();
+
+    Ok(total)
+}
+
+sample! {
+    r"
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX",
+    part1 = 18,
+    part2 = 9
+}
@@ -0,0 +1,148 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    error::{AocError, Result},
+    input::{sections, Pattern, Section},
+    sample,
+};
+use tracing::debug;
+
+type RuleMap = HashMap<i32, HashSet<i32>>;
+
+fn parse_rules(section: &Section) -> Result<RuleMap> {
+    let pattern = Pattern::new("{}|{}");
+    let mut rules = RuleMap::new();
+
+    for line in section.lines() {
+        let numbers = pattern.extract::<i32>(line)?;
+        rules.entry(numbers[1]).or_default().insert(numbers[0]);
+    }
+
+    Ok(rules)
+}
+
+fn parse(input: &str) -> Result<(RuleMap, Vec<Vec<i32>>)> {
+    let [rules, updates] = &sections(input)[..] else {
+        return Err(AocError::invalid("Expected rules and updates sections"));
+    };
+
+    let rules = parse_rules(rules)?;
+    debug!("{:?}", rules);
+
+    let updates = updates
+        .lines()
+        .iter()
+        .map(|line| line.comma_separated())
+        .collect::<Result<_>>()?;
+
+    Ok((rules, updates))
+}
+
+fn is_valid(rules: &RuleMap, pages: &[i32]) -> bool {
+    let mut forbidden: HashSet<i32> = HashSet::new();
+
+    for p in pages.iter() {
+        if forbidden.contains(p) {
+            return false;
+        }
+
+        forbidden.extend(rules.get(p).unwrap_or(&HashSet::new()));
+    }
+
+    true
+}
+
+fn get_middle(pages: &[i32]) -> i32 {
+    *pages.get(pages.len() / 2).unwrap()
+}
+
+pub fn part1(input: &str) -> Result<i32> {
+    let (rules, updates) = parse(input)?;
+
+    let total: i32 = updates
+        .iter()
+        .filter(|pages| is_valid(&rules, pages))
+        .map(|pages| get_middle(pages))
+        .sum();
+
+    Ok(total)
+}
+
+fn find_valid_order(rules: &RuleMap, pages: &[i32]) -> Vec<i32> {
+    let mut page_rules = RuleMap::new();
+    let page_set: HashSet<i32> = HashSet::from_iter(pages.iter().copied());
+
+    for p in pages {
+        page_rules.insert(
+            *p,
+            match rules.get(p) {
+                Some(r) => HashSet::<i32>::from_iter(r.intersection(&page_set).copied()),
+                None => HashSet::new(),
+            },
+        );
+    }
+
+    let mut result = Vec::new();
+
+    while !page_rules.is_empty() {
+        let clear = *page_rules
+            .iter()
+            .find_map(|(k, v)| if v.is_empty() { Some(k) } else { None })
+            .expect("Failed to find next choice");
+
+        result.push(clear);
+        page_rules.remove(&clear);
+        page_rules.values_mut().for_each(|r| {
+            r.remove(&clear);
+        });
+    }
+
+    result
+}
+
+pub fn part2(input: &str) -> Result<i32> {
+    let (rules, updates) = parse(input)?;
+
+    let total: i32 = updates
+        .iter()
+        .filter(|pages| !is_valid(&rules, pages))
+        .map(|pages| find_valid_order(&rules, pages))
+        .map(|pages| get_middle(&pages))
+        .sum();
+
+    Ok(total)
+}
+
+sample! {
+    r"
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47",
+    part1 = 143,
+    part2 = 123
+}
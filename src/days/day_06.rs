@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+
+use crate::{
+    collections::grid::{get_direction_delta, Color, Direction, Grid, Markers},
+    error::Result,
+    sample,
+};
+use tracing::debug;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct Position {
+    row: usize,
+    col: usize,
+    dir: Direction,
+}
+
+fn parse(input: &str) -> Result<(Grid<char>, Position)> {
+    let (grid, markers): (_, Markers) = Grid::parse_with_markers(input, "^", '.')?;
+    let (row, col) = markers.single('^')?;
+
+    Ok((
+        grid,
+        Position {
+            row,
+            col,
+            dir: Direction::North,
+        },
+    ))
+}
+
+fn advance(grid: &Grid<char>, pos: &Position) -> Option<Position> {
+    let mut dir = pos.dir;
+
+    loop {
+        let (dr, dc) = get_direction_delta(dir);
+        let next = grid.position(pos.row as isize + dr, pos.col as isize + dc)?;
+
+        if *next.value() != '#' {
+            return Some(Position {
+                row: next.row(),
+                col: next.col(),
+                dir,
+            });
+        }
+
+        dir = dir.turn_right();
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let (grid, start) = parse(input)?;
+
+    let mut pos = Some(start);
+    let mut seen = HashSet::new();
+
+    while let Some(p) = pos {
+        seen.insert((p.row, p.col));
+        pos = advance(&grid, &p);
+    }
+
+    debug!(
+        "Guard route\n{}",
+        grid.render()
+            .mark(seen.iter().copied(), 'X', Color::Yellow)
+            .ansi(true)
+    );
+
+    Ok(seen.len())
+}
+
+fn is_loop(grid: &Grid<char>, start: &Position, row: usize, col: usize) -> bool {
+    // Can't replace a non-empty tile or the guard's starting point
+    if *grid.get(row, col) != '.' || (row, col) == (start.row, start.col) {
+        return false;
+    }
+
+    let mut grid = grid.clone();
+    *grid.get_mut(row, col) = '#';
+
+    let mut seen = HashSet::new();
+
+    let mut position = Some(*start);
+    while let Some(pos) = position {
+        if seen.contains(&pos) {
+            return true;
+        }
+        seen.insert(pos);
+        position = advance(&grid, &pos);
+    }
+
+    false
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let (grid, start) = parse(input)?;
+
+    let count = grid
+        .enumerate()
+        .filter(|(r, c, _)| is_loop(&grid, &start, *r, *c))
+        .count();
+
+    Ok(count)
+}
+
+sample! {
+    r"
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...",
+    part1 = 41,
+    part2 = 6
+}
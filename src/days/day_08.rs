@@ -0,0 +1,128 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    collections::{grid::Grid, point::Point},
+    error::Result,
+    sample,
+};
+use tracing::info;
+
+struct Antennas {
+    grid: Grid<char>,
+    positions: HashMap<char, Vec<Point>>,
+}
+
+impl Antennas {
+    fn parse(input: &str) -> Result<Self> {
+        let grid: Grid<char> = Grid::parse(input)?;
+        let mut positions: HashMap<char, Vec<Point>> = HashMap::new();
+
+        for (point, signal) in grid.to_sparse(|c| *c != '.').iter() {
+            positions.entry(*signal).or_default().push(point);
+        }
+
+        Ok(Antennas { grid, positions })
+    }
+
+    fn signals(&self) -> impl Iterator<Item = char> + use<'_> {
+        self.positions.keys().copied()
+    }
+
+    fn is_valid_position(&self, pos: &Point) -> bool {
+        self.grid.position_at(*pos).is_some()
+    }
+
+    fn get_anti_nodes(&self, signal: char) -> Vec<Point> {
+        let mut result = Vec::new();
+
+        let antenna_positions = self.positions.get(&signal).expect("Unknown signal");
+
+        for (i, p1) in antenna_positions.iter().enumerate() {
+            for p2 in antenna_positions[i + 1..].iter() {
+                info!(
+                    "Calculating Antinodes for signal {}, Positions {} {}",
+                    signal, p1, p2
+                );
+
+                let delta = *p2 - *p1;
+                result.push(*p2 + delta);
+                result.push(*p1 - delta);
+            }
+        }
+
+        result.retain(|pos| self.is_valid_position(pos));
+
+        result
+    }
+
+    fn get_all_linear_anti_nodes(&self, signal: char) -> Vec<Point> {
+        let mut result = Vec::new();
+
+        let antenna_positions = self.positions.get(&signal).expect("Unknown signal");
+
+        for (i, p1) in antenna_positions.iter().enumerate() {
+            for p2 in antenna_positions[i + 1..].iter() {
+                info!(
+                    "Calculating Antinodes for signal {}, Positions {} {}",
+                    signal, p1, p2
+                );
+
+                let delta = *p2 - *p1;
+
+                let mut pos = *p2;
+                while self.is_valid_position(&pos) {
+                    result.push(pos);
+                    pos += delta;
+                }
+
+                pos = *p1;
+                while self.is_valid_position(&pos) {
+                    result.push(pos);
+                    pos -= delta;
+                }
+            }
+        }
+
+        result
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let antennas = Antennas::parse(input)?;
+
+    let antinodes: HashSet<Point> = antennas
+        .signals()
+        .flat_map(|c| antennas.get_anti_nodes(c))
+        .collect();
+
+    Ok(antinodes.len())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let antennas = Antennas::parse(input)?;
+
+    let antinodes: HashSet<Point> = antennas
+        .signals()
+        .flat_map(|c| antennas.get_all_linear_anti_nodes(c))
+        .collect();
+
+    Ok(antinodes.len())
+}
+
+sample! {
+    r"
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............",
+    part1 = 14,
+    part2 = 34
+}
@@ -0,0 +1,74 @@
+use crate::{
+    collections::grid::{Grid, CARDINAL_DIRECTIONS},
+    error::Result,
+    sample,
+    search::{bfs, SearchResult},
+};
+
+type Point = (usize, usize);
+
+struct TrailMap {
+    grid: Grid<u32>,
+}
+
+impl TrailMap {
+    fn parse(input: &str) -> Result<Self> {
+        let grid = Grid::parse_with(input, |c| c.to_digit(10).unwrap_or(u32::MAX))?;
+
+        Ok(Self { grid })
+    }
+
+    fn uphill(&self, point: &Point) -> Vec<Point> {
+        let pos = self.grid.position(point.0, point.1).unwrap();
+        pos.get_neighbors(&CARDINAL_DIRECTIONS)
+            .filter(|n| *n.value() == *pos.value() + 1)
+            .map(|n| n.into())
+            .collect()
+    }
+
+    fn cells(&self, height: u32) -> Vec<Point> {
+        self.grid
+            .enumerate()
+            .filter(|(_, _, v)| **v == height)
+            .map(|(r, c, _)| (r, c))
+            .collect()
+    }
+
+    /// Each step climbs by exactly one, so every trail to a peak is a shortest
+    /// path from its trailhead.
+    fn score(&self, scorer: impl Fn(&SearchResult<Point>, &Point) -> usize) -> usize {
+        let peaks = self.cells(9);
+
+        self.cells(0)
+            .into_iter()
+            .map(|head| {
+                let trails = bfs([head], |p| self.uphill(p));
+                peaks.iter().map(|p| scorer(&trails, p)).sum::<usize>()
+            })
+            .sum()
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let map = TrailMap::parse(input)?;
+    Ok(map.score(|trails, peak| usize::from(trails.distance(peak).is_some())))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let map = TrailMap::parse(input)?;
+    Ok(map.score(|trails, peak| trails.count_paths_to(peak)))
+}
+
+sample! {
+    r"
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732",
+    part1 = 36,
+    part2 = 81
+}
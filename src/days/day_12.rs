@@ -0,0 +1,79 @@
+use crate::{collections::grid::Grid, error::Result, sample};
+use tracing::info;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let plants: Grid<char> = Grid::parse(input)?;
+
+    let total = plants
+        .regions(|a, b| a == b)
+        .iter()
+        .map(|r| {
+            let cost = r.area() * r.perimeter;
+            info!(
+                "Region {} => {} * {} = {}",
+                r.id,
+                r.area(),
+                r.perimeter,
+                cost
+            );
+            cost
+        })
+        .sum();
+
+    Ok(total)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let plants: Grid<char> = Grid::parse(input)?;
+
+    let total = plants
+        .regions(|a, b| a == b)
+        .iter()
+        .map(|r| {
+            let cost = r.area() * r.sides;
+            info!(
+                "Region {} => Size {} * Sides {} = {}",
+                r.id,
+                r.area(),
+                r.sides,
+                cost
+            );
+            cost
+        })
+        .sum();
+
+    Ok(total)
+}
+
+sample! {
+    large: r"
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE",
+    part1 = 1930,
+    part2 = 1206;
+
+    enclosed: r"
+AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA",
+    part2 = 368;
+
+    stripes: r"
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE",
+    part2 = 236
+}
@@ -0,0 +1,117 @@
+use crate::{
+    collections::point::Point,
+    error::{AocError, Result},
+    input::{sections, Line, Pattern, Section},
+    sample,
+};
+use num::Integer;
+use tracing::info;
+
+#[derive(Debug)]
+struct ClawMachine {
+    a: Point,
+    b: Point,
+    target: Point,
+}
+
+impl ClawMachine {
+    fn parse(section: &Section) -> Result<Self> {
+        let [a, b, target] = section.lines() else {
+            return Err(section.lines()[0].error("Incomplete claw machine"));
+        };
+
+        Ok(Self {
+            a: Self::read_point("Button A: X+{}, Y+{}", a)?,
+            b: Self::read_point("Button B: X+{}, Y+{}", b)?,
+            target: Self::read_point("Prize: X={}, Y={}", target)?,
+        })
+    }
+
+    fn read_point(pattern: &str, line: &Line) -> Result<Point> {
+        let nums = Pattern::new(pattern).extract(line)?;
+        Ok(Point::new(nums[0], nums[1]))
+    }
+
+    fn find_cost(&self) -> Option<i64> {
+        let Point { x: ax, y: ay } = self.a;
+        let Point { x: bx, y: by } = self.b;
+        let Point { x: tx, y: ty } = self.target;
+
+        let m = ax.lcm(&ay);
+        let mx = m / ax;
+        let my = m / ay;
+
+        let bxm = bx * mx;
+        let bym = by * my;
+
+        let txm = tx * mx;
+        let tym = ty * my;
+
+        let (b, brem) = (tym - txm).div_rem(&(bym - bxm));
+        let (a, arem) = (tx - (b * bx)).div_rem(&ax);
+
+        if brem == 0 && arem == 0 {
+            info!("Found solution at A={}, B={}", a, b);
+            Some(a * 3 + b)
+        } else {
+            info!("No solution!");
+            None
+        }
+    }
+}
+
+fn parse_machines(input: &str) -> Result<Vec<ClawMachine>> {
+    let machines: Vec<_> = sections(input)
+        .iter()
+        .map(ClawMachine::parse)
+        .collect::<Result<_>>()?;
+
+    if machines.is_empty() {
+        return Err(AocError::invalid("No claw machines"));
+    }
+
+    Ok(machines)
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+    let machines = parse_machines(input)?;
+
+    let total: i64 = machines.iter().filter_map(|m| m.find_cost()).sum();
+
+    Ok(total)
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+    let mut machines = parse_machines(input)?;
+
+    const SHIFT: Point = Point::new(10000000000000, 10000000000000);
+
+    for m in machines.iter_mut() {
+        m.target += SHIFT;
+    }
+
+    let total: i64 = machines.iter().filter_map(|m| m.find_cost()).sum();
+
+    Ok(total)
+}
+
+sample! {
+    r"
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279",
+    part1 = 480,
+    part2 = 875318608908
+}
@@ -0,0 +1,243 @@
+use crate::{
+    collections::{
+        grid::{Grid, Render},
+        point::Point,
+    },
+    context::Context,
+    error::{AocError, Result},
+    input::{lines, Line},
+    sample,
+};
+use tracing::{debug, info, trace};
+
+struct Robot {
+    pos: Point,
+    vel: Point,
+}
+
+impl Robot {
+    fn parse(line: &Line) -> Result<Self> {
+        let nums = line.numbers::<i64>()?;
+        if nums.len() != 4 {
+            return Err(line.error(format!("Failed to parse robot: {}", line.text)));
+        }
+
+        let pos = Point::new(nums[0], nums[1]);
+        let vel = Point::new(nums[2], nums[3]);
+
+        Ok(Self { pos, vel })
+    }
+
+    fn parse_all(input: &str) -> Result<Vec<Self>> {
+        lines(input).map(|l| Self::parse(&l)).collect()
+    }
+
+    fn get_position(&self, t: i64, bounds: Point) -> Point {
+        (self.pos + self.vel * t).rem_euclid(bounds)
+    }
+
+    fn advance(&mut self, bounds: Point) {
+        self.pos = self.get_position(1, bounds);
+    }
+
+    /// Grid cell of a robot in a `RoboGrid`. It can't be off the grid, as
+    /// `RoboGrid::new` checks where robots start and `advance` wraps them
+    /// around the edges.
+    fn cell(&self) -> (usize, usize) {
+        self.pos.to_grid().expect("Robot left the room")
+    }
+}
+
+struct RoboGrid {
+    robots: Vec<Robot>,
+    grid: Grid<i32>,
+}
+
+impl RoboGrid {
+    fn new(robots: Vec<Robot>, bounds: Point) -> Result<Self> {
+        let mut grid = Grid::new(bounds.y as usize, bounds.x as usize, 0);
+
+        info!(
+            "Created grid with {} rows and {} cols",
+            grid.rows(),
+            grid.cols()
+        );
+
+        for bot in robots.iter() {
+            let (r, c) = grid
+                .position(bot.pos.y, bot.pos.x)
+                .map(|p| (p.row(), p.col()))
+                .ok_or_else(|| {
+                    AocError::invalid(format!(
+                        "Robot at {},{} is outside the {}x{} room",
+                        bot.pos.x, bot.pos.y, bounds.x, bounds.y
+                    ))
+                })?;
+            *grid.get_mut(r, c) += 1;
+        }
+
+        Ok(Self { robots, grid })
+    }
+
+    fn advance(&mut self) {
+        for bot in self.robots.iter_mut() {
+            let (r, c) = bot.cell();
+            *self.grid.get_mut(r, c) -= 1;
+
+            bot.advance(Point::new(self.grid.cols() as i64, self.grid.rows() as i64));
+
+            let (r, c) = bot.cell();
+            *self.grid.get_mut(r, c) += 1;
+        }
+    }
+
+    fn render(&self) -> Render<'_, i32> {
+        self.grid
+            .render_with(|v| if *v > 0 { "+" } else { "." }.to_string())
+    }
+
+    fn detect_tree(&self) -> usize {
+        // Note: The assumed shape turned out to be wildly incorrect, but in the
+        // process of trying to find heuristics the below check for contiguous
+        // rows seems to be good enough.
+
+        // Assumed tree shape
+        // ....+....
+        // ...+.+...
+        // ..+...+..
+        // .+++++++.
+        // ....+....
+
+        // Heuristic: Search for the bottom line.
+        let mut longest_row = 0;
+
+        for row in self.grid.row_wise_iter() {
+            let mut contiguous = 0;
+            for col in row {
+                if *col > 0 {
+                    contiguous += 1;
+                } else {
+                    longest_row = longest_row.max(contiguous);
+                    contiguous = 0;
+                }
+            }
+            longest_row = longest_row.max(contiguous);
+        }
+
+        longest_row as usize
+    }
+
+    fn search_tree(&mut self) -> usize {
+        let mut t = 0;
+
+        debug!("Initial positions\n{}", self.render());
+        loop {
+            t += 1;
+            self.advance();
+            trace!("Time {}\n{}", t, self.render());
+            let bots_in_tree = self.detect_tree();
+            debug!("Time {} - Bots in tree {}", t, bots_in_tree);
+
+            if bots_in_tree > 10 {
+                info!("There are {} bots in the tree. Stopping.", bots_in_tree);
+                info!("Tree found\n{}", self.render());
+                break;
+            }
+        }
+
+        t
+    }
+}
+
+fn solve(input: &str, bounds: Point) -> Result<i32> {
+    let bots = Robot::parse_all(input)?;
+
+    let mid_x = bounds.x / 2;
+    let mid_y = bounds.y / 2;
+
+    let mut quadrants = [0, 0, 0, 0];
+    for bot in bots {
+        let Point { x, y } = bot.get_position(100, bounds);
+        info!("Bot located at {}, {}", x, y);
+
+        if x < mid_x && y < mid_y {
+            quadrants[0] += 1;
+        } else if x > mid_x && y < mid_y {
+            quadrants[1] += 1;
+        } else if x < mid_x && y > mid_y {
+            quadrants[2] += 1;
+        } else if x > mid_x && y > mid_y {
+            quadrants[3] += 1;
+        }
+    }
+
+    let total = quadrants.iter().copied().reduce(|acc, v| acc * v).unwrap();
+
+    Ok(total)
+}
+
+fn solve2(input: &str, bounds: Point) -> Result<usize> {
+    let bots = Robot::parse_all(input)?;
+    let mut robogrid = RoboGrid::new(bots, bounds)?;
+
+    let t = robogrid.search_tree();
+
+    Ok(t)
+}
+
+fn dimensions(ctx: &Context) -> Result<Point> {
+    Ok(Point::new(
+        ctx.param("width")?.unwrap_or(101),
+        ctx.param("height")?.unwrap_or(103),
+    ))
+}
+
+pub fn part1(input: &str, ctx: &Context) -> Result<i32> {
+    solve(input, dimensions(ctx)?)
+}
+
+pub fn part2(input: &str, ctx: &Context) -> Result<usize> {
+    solve2(input, dimensions(ctx)?)
+}
+
+sample! {
+    r"
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3",
+    part1(width = 11, height = 7) = 12
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_reports_line() {
+        let err = Robot::parse_all("p=0,4 v=3,-3\np=6,3 v=-1").err().unwrap();
+        assert!(matches!(err, AocError::Parse { line: 2, .. }));
+
+        let err = Robot::parse_all("p=0,99999999999999999999 v=3,-3")
+            .err()
+            .unwrap();
+        assert!(matches!(err, AocError::Parse { line: 1, .. }));
+    }
+
+    #[test]
+    fn test_robot_outside_room() {
+        let err = solve2("p=11,4 v=3,-3", Point::new(11, 7)).unwrap_err();
+        assert_eq!(
+            "invalid input: Robot at 11,4 is outside the 11x7 room",
+            err.to_string()
+        );
+    }
+}
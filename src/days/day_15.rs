@@ -0,0 +1,301 @@
+use std::collections::VecDeque;
+
+use crate::{
+    collections::grid::{Direction, Grid, Position},
+    error::{AocError, Result},
+    input::{sections, Section},
+    sample,
+};
+use tracing::{debug, info, trace};
+
+type Swap = ((usize, usize), (usize, usize));
+
+struct Warehouse {
+    grid: Grid<char>,
+    bot: (usize, usize),
+    moves: VecDeque<Direction>,
+    wide: bool,
+}
+
+impl Warehouse {
+    fn parse(input: &str, wide: bool) -> Result<Self> {
+        let [layout, directions] = &sections(input)[..] else {
+            return Err(AocError::invalid("Expected warehouse and moves sections"));
+        };
+
+        let (mut grid, markers) = layout.parse_grid_with_markers::<char>("@", '.')?;
+        let (r, c) = markers.single('@')?;
+        *grid.get_mut(r, c) = '@';
+        let (grid, bot) = if wide {
+            (Self::widen(layout, &grid)?, (r, 2 * c))
+        } else {
+            (grid, (r, c))
+        };
+
+        let mut moves = VecDeque::new();
+        for line in directions.lines() {
+            for (offset, c) in line.text.char_indices() {
+                let dir = Direction::try_from(c)
+                    .map_err(|_| line.error_at(offset, format!("Unrecognized move: {}", c)))?;
+                moves.push_back(dir);
+            }
+        }
+
+        Ok(Self {
+            grid,
+            bot,
+            moves,
+            wide,
+        })
+    }
+
+    fn widen(layout: &Section, grid: &Grid<char>) -> Result<Grid<char>> {
+        let mut rows = Vec::with_capacity(grid.rows());
+
+        for (r, line) in layout.lines().iter().enumerate() {
+            let mut row = Vec::with_capacity(grid.cols() * 2);
+            for (v, (offset, _)) in grid.row(r).iter().zip(line.text.char_indices()) {
+                row.extend(match v {
+                    '#' => ['#', '#'],
+                    'O' => ['[', ']'],
+                    '.' => ['.', '.'],
+                    '@' => ['@', '.'],
+                    x => return Err(line.error_at(offset, format!("Unrecognized block: {}", x))),
+                });
+            }
+            rows.push(row);
+        }
+
+        Ok(rows.into_iter().map(|r| r.into_iter()).collect())
+    }
+
+    fn count_boxes(&self) -> usize {
+        self.grid
+            .enumerate()
+            .filter(|(_, _, v)| matches!(v, 'O' | '['))
+            .count()
+    }
+
+    fn shift(&mut self, r: usize, c: usize, dir: Direction) -> (usize, usize) {
+        // Empty nodes don't need to be shifted. Walls cannot be shifted.
+        let val = *self.grid.get(r, c);
+        if val == '.' || val == '#' {
+            return (r, c);
+        }
+
+        let pos = self.grid.position(r, c).unwrap();
+        let neighbor = pos.get_neighbor(dir);
+        let (r, c) = pos.into();
+
+        if let Some(n) = neighbor {
+            let (rn, cn) = n.into();
+            self.shift(rn, cn, dir);
+            if *self.grid.get(rn, cn) == '.' {
+                debug!("Moving {} from ({}, {}) to ({}, {})", val, r, c, rn, cn);
+                // Shift is possible, swap and return new position.
+                *self.grid.get_mut(rn, cn) = *self.grid.get(r, c);
+                *self.grid.get_mut(r, c) = '.';
+                return (rn, cn);
+            }
+        }
+
+        (r, c)
+    }
+
+    fn shift_wide(pos: Position<char>, dir: Direction) -> Option<Vec<Swap>> {
+        let n1 = pos.get_neighbor(dir);
+
+        match pos.value() {
+            '#' => None,
+            '.' => Some(Vec::new()),
+            '@' => {
+                let n1 = n1.unwrap();
+                let swap = ((pos.row(), pos.col()), (n1.row(), n1.col()));
+                if let Some(mut swaps) = Self::shift_wide(n1, dir) {
+                    swaps.push(swap);
+                    Some(swaps)
+                } else {
+                    None
+                }
+            }
+            '[' => {
+                let p2 = pos.get_neighbor(Direction::East).unwrap();
+                let n1 = n1.unwrap();
+                let n2 = n1.get_neighbor(Direction::East).unwrap();
+
+                let swap = ((pos.row(), pos.col()), (n1.row(), n1.col()));
+                let swap2 = ((p2.row(), p2.col()), (n2.row(), n2.col()));
+
+                let s1 = Self::shift_wide(n1, dir);
+                let s2 = Self::shift_wide(n2, dir);
+                if let (Some(mut swaps), Some(other)) = (s1, s2) {
+                    debug!("S1 {:?}, S2 {:?}", swaps, other);
+                    swaps.extend_from_slice(&other);
+                    swaps.push(swap);
+                    swaps.push(swap2);
+                    Some(swaps)
+                } else {
+                    None
+                }
+            }
+            ']' => {
+                let p2 = pos.get_neighbor(Direction::West).unwrap();
+                let n1 = n1.unwrap();
+                let n2 = n1.get_neighbor(Direction::West).unwrap();
+
+                let swap = ((pos.row(), pos.col()), (n1.row(), n1.col()));
+                let swap2 = ((p2.row(), p2.col()), (n2.row(), n2.col()));
+
+                let s1 = Self::shift_wide(n1, dir);
+                let s2 = Self::shift_wide(n2, dir);
+                if let (Some(mut swaps), Some(other)) = (s1, s2) {
+                    debug!("S1 {:?}, S2 {:?}", swaps, other);
+                    swaps.extend_from_slice(&other);
+                    swaps.push(swap);
+                    swaps.push(swap2);
+                    Some(swaps)
+                } else {
+                    None
+                }
+            }
+            x => panic!("Unexpected block in shift {}", x),
+        }
+    }
+
+    fn run(&mut self) {
+        let (mut rbot, mut cbot) = self.bot;
+
+        let box_count = self.count_boxes();
+
+        while let Some(dir) = self.moves.pop_front() {
+            info!("Moving {:?}", dir);
+
+            if self.wide && matches!(dir, Direction::North | Direction::South) {
+                let pos = self.grid.position(rbot, cbot).unwrap();
+                match Self::shift_wide(pos, dir) {
+                    Some(mut swaps) => {
+                        info!("There are {} swaps required.", swaps.len());
+
+                        swaps.sort();
+                        if matches!(dir, Direction::South) {
+                            swaps.reverse();
+                        }
+
+                        swaps.dedup();
+                        info!("There are {} unique swaps.", swaps.len());
+
+                        for ((r, c), (rn, cn)) in swaps {
+                            debug!("Moving from ({}, {}) to ({}, {})", r, c, rn, cn);
+                            // Shift is possible, swap and return new position.
+                            *self.grid.get_mut(rn, cn) = *self.grid.get(r, c);
+                            *self.grid.get_mut(r, c) = '.';
+                        }
+
+                        (rbot, cbot) = self
+                            .grid
+                            .position(rbot, cbot)
+                            .unwrap()
+                            .get_neighbor(dir)
+                            .unwrap()
+                            .into();
+                    }
+                    None => {
+                        debug!("No move possible.");
+                    }
+                }
+            } else {
+                (rbot, cbot) = self.shift(rbot, cbot, dir);
+            }
+            trace!("Grid state\n{}", self.grid);
+
+            let new_count = self.count_boxes();
+            if box_count != new_count {
+                panic!("Lost a box!");
+            }
+        }
+    }
+
+    fn score(&self) -> usize {
+        self.grid
+            .enumerate()
+            .filter_map(|(r, c, v)| match v {
+                'O' | '[' => Some(100 * r + c),
+                _ => None,
+            })
+            .sum()
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let mut warehouse = Warehouse::parse(input, false)?;
+    info!(
+        "Warehouse size is {}, {}",
+        warehouse.grid.rows(),
+        warehouse.grid.cols()
+    );
+    warehouse.run();
+    Ok(warehouse.score())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let mut warehouse = Warehouse::parse(input, true)?;
+    info!(
+        "Warehouse size is {}, {}",
+        warehouse.grid.rows(),
+        warehouse.grid.cols()
+    );
+    warehouse.run();
+    Ok(warehouse.score())
+}
+
+sample! {
+    large: r"
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^",
+    part1 = 10092,
+    part2 = 9021;
+
+    small: r"
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<",
+    part1 = 2028;
+
+    wide: r"
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^",
+    part2 = 618
+}
@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use crate::{
+    collections::grid::{get_direction_delta, Color, Direction, Grid},
+    error::{AocError, Result},
+    sample,
+    search::{dijkstra, SearchResult},
+};
+use tracing::{debug, info};
+
+type Point = (usize, usize);
+type State = (Point, Direction);
+
+struct Maze {
+    grid: Grid<char>,
+    start: Point,
+    end: Point,
+}
+
+impl Maze {
+    fn parse(input: &str) -> Result<Self> {
+        let (grid, markers) = Grid::parse_with_markers(input, "SE", '.')?;
+        let start = markers.single('S')?;
+        let end = markers.single('E')?;
+
+        Ok(Self { grid, start, end })
+    }
+
+    fn moves(&self, &(point, dir): &State) -> Vec<(State, usize)> {
+        let mut result: Vec<_> = dir
+            .perpendiculars()
+            .into_iter()
+            .map(|turn| ((point, turn), 1000))
+            .collect();
+
+        let (dr, dc) = get_direction_delta(dir);
+        if let Some(step) = self
+            .grid
+            .position(point.0 as isize + dr, point.1 as isize + dc)
+        {
+            if *step.value() != '#' {
+                result.push(((step.into(), dir), 1));
+            }
+        }
+
+        result
+    }
+
+    /// Lowest score to the end along with the end states that achieve it.
+    fn solve(&self) -> Result<(usize, SearchResult<State>, Vec<State>)> {
+        let result = dijkstra([(self.start, Direction::East)], |s| self.moves(s));
+
+        let ends: Vec<_> = result
+            .distances()
+            .iter()
+            .filter(|((p, _), _)| *p == self.end)
+            .map(|(s, d)| (*s, *d))
+            .collect();
+
+        let low_score = ends
+            .iter()
+            .map(|(_, d)| *d)
+            .min()
+            .ok_or(AocError::no_solution("End is unreachable"))?;
+        debug!("Lowest score is {}", low_score);
+
+        let best_ends = ends
+            .into_iter()
+            .filter(|(_, d)| *d == low_score)
+            .map(|(s, _)| s)
+            .collect();
+
+        Ok((low_score, result, best_ends))
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let maze = Maze::parse(input)?;
+    Ok(maze.solve()?.0)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let maze = Maze::parse(input)?;
+    let (_, result, ends) = maze.solve()?;
+
+    let unique: HashSet<Point> = result
+        .states_on_paths_to(ends.iter())
+        .into_iter()
+        .map(|(p, _)| p)
+        .collect();
+    info!("There are {} tiles on the best paths.", unique.len());
+
+    debug!(
+        "Best paths\n{}",
+        maze.grid
+            .render()
+            .mark(unique.iter().copied(), 'O', Color::Green)
+            .ansi(true)
+    );
+
+    Ok(unique.len())
+}
+
+sample! {
+    r"
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############",
+    part1 = 7036,
+    part2 = 45
+}
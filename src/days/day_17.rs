@@ -0,0 +1,42 @@
+use crate::{
+    computer::{disassemble, find_quines, Computer},
+    context::Context,
+    error::Result,
+    sample,
+};
+use tracing::{debug, info};
+
+pub fn part1(input: &str) -> Result<Vec<u8>> {
+    let mut computer = Computer::parse(input)?;
+    debug!("Program:\n{}", disassemble(computer.program())?);
+
+    Ok(computer.run_to_end()?.to_vec())
+}
+
+pub fn part2(input: &str, ctx: &Context) -> Result<u64> {
+    let computer = Computer::parse(input)?;
+    // Brute force bound, only used if the program isn't a simple loop
+    let limit = ctx.param("limit")?.unwrap_or(1 << 24);
+    let quines = find_quines(&computer, limit)?;
+    info!("Found {} quines: {:?}", quines.len(), quines);
+
+    Ok(quines[0])
+}
+
+sample! {
+    output: r"
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0",
+    part1 = [4, 6, 3, 5, 6, 3, 5, 2, 1, 0];
+
+    quine: r"
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0",
+    part2 = 117440
+}
@@ -0,0 +1,132 @@
+use crate::{
+    collections::grid::Grid,
+    context::Context,
+    error::{AocError, Result},
+    input::{lines, Line},
+    sample,
+    search::grid_bfs,
+};
+use tracing::info;
+
+/// Steps from the top left to the bottom right corner, if it is reachable.
+fn search(grid: &Grid<char>) -> Option<usize> {
+    let exit = (grid.rows() - 1, grid.cols() - 1);
+    let steps = grid_bfs(grid, (0, 0), |v| *v == '#').distance(&exit);
+    info!("Exit reachable in {:?} steps", steps);
+
+    steps
+}
+
+/// Corrupt the cell named by an `X,Y` line, returning its coordinates.
+fn drop_byte(g: &mut Grid<char>, line: &Line) -> Result<Vec<usize>> {
+    let nums = line.numbers::<usize>()?;
+    let [x, y] = nums[..] else {
+        return Err(line.error(format!("Expected X,Y, found {}", line.text)));
+    };
+    if x >= g.cols() || y >= g.rows() {
+        return Err(line.error(format!(
+            "{},{} is outside the {}x{} grid",
+            x,
+            y,
+            g.cols(),
+            g.rows()
+        )));
+    }
+
+    *g.get_mut(y, x) = '#';
+    Ok(nums)
+}
+
+fn solve(input: &str, width: usize, height: usize, depth: usize) -> Result<usize> {
+    let mut g = Grid::new(height, width, '.');
+
+    for line in lines(input).take(depth) {
+        drop_byte(&mut g, &line)?;
+    }
+
+    search(&g).ok_or(AocError::no_solution("Exit is unreachable"))
+}
+
+fn solve2(input: &str, width: usize, height: usize, depth: usize) -> Result<Vec<usize>> {
+    let mut g = Grid::new(height, width, '.');
+
+    let mut lines = lines(input);
+
+    // Initial conditions
+    for line in lines.by_ref().take(depth) {
+        drop_byte(&mut g, &line)?;
+    }
+
+    for next in lines {
+        let nums = drop_byte(&mut g, &next)?;
+
+        if search(&g).is_none() {
+            return Ok(nums);
+        }
+    }
+
+    Err(AocError::no_solution("Exit is never blocked"))
+}
+
+fn parameters(ctx: &Context) -> Result<(usize, usize, usize)> {
+    Ok((
+        ctx.param("width")?.unwrap_or(71),
+        ctx.param("height")?.unwrap_or(71),
+        ctx.param("bytes")?.unwrap_or(1024),
+    ))
+}
+
+pub fn part1(input: &str, ctx: &Context) -> Result<usize> {
+    let (width, height, bytes) = parameters(ctx)?;
+    solve(input, width, height, bytes)
+}
+
+pub fn part2(input: &str, ctx: &Context) -> Result<Vec<usize>> {
+    let (width, height, bytes) = parameters(ctx)?;
+    solve2(input, width, height, bytes)
+}
+
+sample! {
+    r"
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0",
+    part1(width = 7, height = 7, bytes = 12) = 22,
+    part2(width = 7, height = 7, bytes = 12) = [6, 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_bytes() {
+        let err = solve("1,2\n3", 7, 7, 2).unwrap_err();
+        assert_eq!("line 2: Expected X,Y, found 3", err.to_string());
+
+        let err = solve2("1,2\n7,0", 7, 7, 1).unwrap_err();
+        assert_eq!("line 2: 7,0 is outside the 7x7 grid", err.to_string());
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{
+    error::{AocError, Result},
+    input::sections,
+    sample,
+};
+use cached::proc_macro::cached;
+use tracing::debug;
+
+struct Towels {
+    available: Vec<String>,
+    targets: Vec<String>,
+}
+
+#[cached(key = "String", convert = r#"{ String::from(target) }"#)]
+fn try_match_pattern(available: &Vec<String>, target: &str) -> usize {
+    if target.is_empty() {
+        return 1;
+    }
+
+    let mut total = 0;
+    for a in available {
+        if target.starts_with(a) {
+            total += try_match_pattern(available, &target[a.len()..]);
+        }
+    }
+
+    total
+}
+
+impl Towels {
+    fn parse(input: &str) -> Result<Self> {
+        let [available, targets] = &sections(input)[..] else {
+            return Err(AocError::invalid("Expected towels and patterns sections"));
+        };
+
+        let available = available.comma_separated()?;
+        let targets = targets.lines_of()?;
+
+        debug!("Available towels {:?}", available);
+        debug!("Target patterns {:?}", targets);
+
+        Ok(Self { available, targets })
+    }
+
+    fn count_possible(&self) -> usize {
+        self.targets
+            .iter()
+            .filter(|t| try_match_pattern(&self.available, t) > 0)
+            .count()
+    }
+
+    fn count_permutations(&self) -> usize {
+        self.targets
+            .iter()
+            .map(|t| try_match_pattern(&self.available, t))
+            .sum()
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let towels = Towels::parse(input)?;
+    Ok(towels.count_possible())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let towels = Towels::parse(input)?;
+    Ok(towels.count_permutations())
+}
+
+sample! {
+    r"
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb",
+    part1 = 6,
+    part2 = 16
+}
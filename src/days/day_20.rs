@@ -0,0 +1,115 @@
+use crate::{
+    collections::{grid::Grid, point::Point},
+    context::Context,
+    error::{AocError, Result},
+    sample,
+    search::grid_bfs,
+};
+use tracing::{debug, info};
+
+struct PathNode {
+    point: Point,
+    d_start: usize,
+    d_end: usize,
+}
+
+struct Maze {
+    grid: Grid<char>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+impl Maze {
+    fn parse(input: &str) -> Result<Self> {
+        let (grid, markers) = Grid::parse_with_markers(input, "SE", '.')?;
+        let start = markers.single('S')?;
+        let end = markers.single('E')?;
+
+        Ok(Self { grid, start, end })
+    }
+
+    fn distance_from_end(&self) -> Result<(usize, Vec<PathNode>)> {
+        let lengths = grid_bfs(&self.grid, self.end, |v| *v == '#');
+
+        let origin = lengths
+            .distance(&self.start)
+            .ok_or(AocError::no_solution("End is unreachable"))?;
+
+        let nodes = self
+            .grid
+            .enumerate()
+            .filter_map(|(r, c, _)| {
+                let d_end = lengths.distance(&(r, c))?;
+                Some(PathNode {
+                    point: Point::from_grid((r, c)),
+                    d_start: origin.checked_sub(d_end)?,
+                    d_end,
+                })
+            })
+            .collect();
+
+        Ok((origin, nodes))
+    }
+
+    fn find_cheats(&self, max_cheat: usize) -> Result<Vec<usize>> {
+        let (origin, nodes) = self.distance_from_end()?;
+        let mut result = Vec::new();
+
+        for a in nodes.iter() {
+            for b in nodes.iter() {
+                let d = a.point.manhattan(&b.point) as usize;
+                // Only cheat between nodes if the target node is closer to the end.
+                if d > 0 && d <= max_cheat && b.d_end < a.d_end {
+                    let total_distance = a.d_start + d + b.d_end;
+                    debug!(
+                        "Path from {} to {} is length {}",
+                        a.point, b.point, total_distance
+                    );
+                    result.push(origin - total_distance);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+fn solve(input: &str, max_cheat: usize, limit: usize) -> Result<usize> {
+    let maze = Maze::parse(input)?;
+
+    let paths = maze.find_cheats(max_cheat)?;
+    info!("There are {} cheat paths.", paths.len());
+    let count = paths.iter().filter(|v| **v >= limit).count();
+
+    Ok(count)
+}
+
+pub fn part1(input: &str, ctx: &Context) -> Result<usize> {
+    solve(input, 2, ctx.param("limit")?.unwrap_or(100))
+}
+
+pub fn part2(input: &str, ctx: &Context) -> Result<usize> {
+    solve(input, 20, ctx.param("limit")?.unwrap_or(100))
+}
+
+sample! {
+    r"
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############",
+    // Use shorter cutoffs for the sample maze
+    part1(limit = 20) = 5,
+    part2(limit = 70) = 41
+}
@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use crate::{
+    circuit::Circuit,
+    context::Context,
+    error::{AocError, Result},
+    input::{sections, Pattern},
+    sample,
+};
+use tracing::{info, trace};
+
+/// The gates along with the initial value of each input wire.
+fn parse(input: &str) -> Result<(Circuit, HashMap<usize, u64>)> {
+    let [initial, gates] = &sections(input)[..] else {
+        return Err(AocError::invalid(
+            "Expected initial values and gates separated by a blank line",
+        ));
+    };
+
+    let mut circuit = Circuit::parse(gates)?;
+
+    let pattern = Pattern::new("{}: {}");
+    let mut inputs = HashMap::new();
+    for line in initial.lines() {
+        let fields: Vec<String> = pattern.extract(line)?;
+        let value = match fields[1].as_str() {
+            "0" => 0,
+            "1" => 1,
+            x => return Err(line.error(format!("Expected 0 or 1, found {}", x))),
+        };
+        inputs.insert(circuit.add_wire(&fields[0]), value);
+    }
+
+    Ok((circuit, inputs))
+}
+
+fn solve2(input: &str, swaps: usize) -> Result<String> {
+    let (circuit, _) = parse(input)?;
+
+    let violations = circuit.verify_adder()?;
+    for v in violations.iter() {
+        info!("{}: {}", circuit.describe(v.wire), v.rule);
+    }
+
+    let mut wires: Vec<_> = violations.iter().map(|v| v.wire).collect();
+    wires.dedup();
+    trace!("Netlist:\n{}", circuit.to_dot(&wires));
+
+    if wires.len() != swaps * 2 {
+        return Err(AocError::no_solution(format!(
+            "Expected {} mis-wired gates, found {}",
+            swaps * 2,
+            wires.len()
+        )));
+    }
+
+    let mut names: Vec<_> = wires.iter().map(|w| circuit.name(*w)).collect();
+    names.sort();
+    Ok(names.join(","))
+}
+
+pub fn part1(input: &str) -> Result<u64> {
+    let (circuit, inputs) = parse(input)?;
+    let values = circuit.evaluate(&inputs)?;
+
+    Ok(circuit
+        .bus('z')?
+        .iter()
+        .rev()
+        .fold(0, |acc, z| (acc << 1) | values[*z]))
+}
+
+pub fn part2(input: &str, ctx: &Context) -> Result<String> {
+    solve2(input, ctx.param("swaps")?.unwrap_or(4))
+}
+
+sample! {
+    output: r"
+x00: 1
+x01: 0
+x02: 1
+x03: 1
+x04: 0
+y00: 1
+y01: 1
+y02: 1
+y03: 1
+y04: 1
+
+ntg XOR fgs -> mjb
+y02 OR x01 -> tnw
+kwq OR kpj -> z05
+x00 OR x03 -> fst
+tgd XOR rvg -> z01
+vdt OR tnw -> bfw
+bfw AND frj -> z10
+ffh OR nrd -> bqk
+y00 AND y03 -> djm
+y03 OR y00 -> psh
+bqk OR frj -> z08
+tnw OR fst -> frj
+gnj AND tgd -> z11
+bfw XOR mjb -> z00
+x03 OR x00 -> vdt
+gnj AND wpb -> z02
+x04 AND y00 -> kjc
+djm OR pbm -> qhw
+nrd AND vdt -> hwm
+kjc AND fst -> rvg
+y04 OR y02 -> fgs
+y01 AND x02 -> pbm
+ntg OR kjc -> kwq
+psh XOR fgs -> tgd
+qhw XOR tgd -> z09
+pbm OR djm -> kpj
+x03 XOR y03 -> ffh
+x00 XOR y04 -> ntg
+bfw OR bqk -> z06
+nrd XOR fgs -> wpb
+frj XOR qhw -> z04
+bqk OR frj -> z07
+y03 OR x01 -> nrd
+hwm AND bqk -> z03
+tgd XOR rvg -> z12
+tnw OR pbm -> gnj",
+    part1 = 2024;
+
+    swapped: r"
+x00: 1
+x01: 1
+x02: 0
+x03: 1
+y00: 1
+y01: 0
+y02: 1
+y03: 1
+
+x00 XOR y00 -> z00
+x00 AND y00 -> kcn
+x01 XOR y01 -> fhq
+x01 AND y01 -> dmv
+dmv XOR kcn -> z01
+kcn AND dmv -> gwp
+fhq OR gwp -> jrk
+x02 XOR y02 -> mtb
+x02 AND y02 -> nqs
+mtb XOR jrk -> rhw
+jrk AND mtb -> pvd
+nqs OR pvd -> z02
+x03 XOR y03 -> tjc
+x03 AND y03 -> vkm
+tjc XOR rhw -> z03
+rhw AND tjc -> wdf
+vkm OR wdf -> z04",
+    part2(swaps = 2) = "dmv,fhq,rhw,z02"
+}
@@ -0,0 +1,101 @@
+use crate::{
+    collections::grid::Grid,
+    error::{AocError, Result},
+    input::sections,
+    sample,
+};
+use tracing::info;
+
+fn parse_grids(input: &str) -> Result<Vec<Grid<char>>> {
+    sections(input).iter().map(|s| s.parse_grid()).collect()
+}
+
+fn heights(grid: &Grid<char>) -> Vec<usize> {
+    grid.col_wise_iter()
+        .map(|col| col.filter(|c| matches!(c, '#')).count())
+        .collect()
+}
+
+fn is_lock(grid: &Grid<char>) -> bool {
+    grid.row_wise_iter()
+        .next()
+        .unwrap()
+        .all(|c| matches!(c, '#'))
+}
+
+pub fn part1(input: &str) -> Result<i32> {
+    let schematics = parse_grids(input)?;
+
+    let (locks, keys): (Vec<_>, Vec<_>) = schematics.into_iter().partition(is_lock);
+
+    let cutoff = locks
+        .first()
+        .ok_or(AocError::invalid("No locks in input"))?
+        .rows();
+    info!("Cutoff is {}", cutoff);
+
+    let lock_heights: Vec<_> = locks.iter().map(heights).collect();
+    let key_heights: Vec<_> = keys.iter().map(heights).collect();
+
+    let mut total = 0;
+    for l in lock_heights.iter() {
+        for k in key_heights.iter() {
+            if l.iter()
+                .zip(k.iter())
+                .map(|(a, b)| a + b)
+                .all(|v| v <= cutoff)
+            {
+                total += 1;
+            }
+        }
+    }
+
+    info!("There are {} keys.", keys.len());
+    info!("There are {} locks.", locks.len());
+
+    Ok(total)
+}
+
+sample! {
+    r"
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####",
+    part1 = 3
+}
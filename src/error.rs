@@ -0,0 +1,85 @@
+use std::{error::Error, fmt::Display};
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    /// Input could not be parsed. Line and column numbers are 1-based.
+    Parse {
+        line: usize,
+        column: Option<usize>,
+        message: String,
+    },
+    /// Input is malformed in a way that is not tied to a single line.
+    Invalid(String),
+    /// Input was well formed but no answer could be found.
+    NoSolution(String),
+}
+
+impl AocError {
+    /// Parse error for the line at the given 0-based index, as produced by
+    /// `input.lines().enumerate()`.
+    pub fn parse(index: usize, message: impl Into<String>) -> Self {
+        AocError::Parse {
+            line: index + 1,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// Parse error at a 0-based character offset within the line at the given
+    /// 0-based index.
+    pub fn parse_at(index: usize, offset: usize, message: impl Into<String>) -> Self {
+        AocError::Parse {
+            line: index + 1,
+            column: Some(offset + 1),
+            message: message.into(),
+        }
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        AocError::Invalid(message.into())
+    }
+
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        AocError::NoSolution(message.into())
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Parse {
+                line,
+                column: None,
+                message,
+            } => write!(f, "line {}: {}", line, message),
+            AocError::Parse {
+                line,
+                column: Some(column),
+                message,
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+            AocError::Invalid(message) => write!(f, "invalid input: {}", message),
+            AocError::NoSolution(message) => write!(f, "no solution: {}", message),
+        }
+    }
+}
+
+impl Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_line_is_one_based() {
+        let err = AocError::parse(0, "Unexpected token");
+        assert_eq!("line 1: Unexpected token", err.to_string());
+    }
+
+    #[test]
+    fn test_parse_error_column() {
+        let err = AocError::parse_at(2, 4, "Unexpected token");
+        assert_eq!("line 3, column 5: Unexpected token", err.to_string());
+    }
+}
@@ -1,21 +1,288 @@
-use std::{fmt::Debug, str::FromStr};
+use std::{
+    fmt::{Debug, Display},
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 use lazy_static::lazy_static;
 use regex::Regex;
 
+use crate::{
+    collections::grid::{Grid, Markers},
+    error::{AocError, Result},
+};
+
 lazy_static! {
     static ref RE_INTEGERS: Regex = Regex::new(r"(-?\d+)").unwrap();
 }
 
+pub const INPUTS_DIR: &str = "inputs";
+
+/// Conventional input location for a puzzle, e.g. `inputs/day_01.txt`.
+pub fn default_input_path(name: &str) -> PathBuf {
+    Path::new(INPUTS_DIR).join(format!("{}.txt", name))
+}
+
+/// Read puzzle input from a file, or from stdin when the path is `-`. Trailing
+/// whitespace is stripped, as it is from samples.
+pub fn read_input(path: &Path) -> io::Result<String> {
+    let mut contents = if path == Path::new("-") {
+        let mut contents = String::new();
+        io::stdin().read_to_string(&mut contents)?;
+        contents
+    } else {
+        fs::read_to_string(path)?
+    };
+
+    contents.truncate(contents.trim_end().len());
+    Ok(contents)
+}
+
+/// All integers in the string. Panics if one doesn't fit in `T`; prefer
+/// `Line::numbers`, which reports where the bad number is.
 pub fn get_all_numbers<T>(s: &str) -> Vec<T>
 where
     T: FromStr,
-    <T as FromStr>::Err: Debug,
+    <T as FromStr>::Err: Display,
+{
+    Line { index: 0, text: s }
+        .numbers()
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+fn parse_field<T>(line: &Line, offset: usize, field: &str) -> Result<T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    field
+        .parse()
+        .map_err(|e| line.error_at(offset, format!("Invalid value {:?}: {}", field, e)))
+}
+
+/// A single line of input along with its 0-based index, so that parse errors
+/// can report where they happened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub index: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn error(&self, message: impl Into<String>) -> AocError {
+        AocError::parse(self.index, message)
+    }
+
+    /// Parse error at a byte offset into the line.
+    pub fn error_at(&self, offset: usize, message: impl Into<String>) -> AocError {
+        let column = self.text[..offset].chars().count();
+        AocError::parse_at(self.index, column, message)
+    }
+
+    /// Parse the whole line, ignoring surrounding whitespace.
+    pub fn parse<T>(&self) -> Result<T>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        let field = self.text.trim();
+        let offset = self.text.len() - self.text.trim_start().len();
+        parse_field(self, offset, field)
+    }
+
+    /// Parse a comma separated list such as `75,47,61` or `r, wr, b`.
+    pub fn comma_separated<T>(&self) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        let mut offset = 0;
+        self.text
+            .split(',')
+            .map(|field| {
+                let start = offset + field.len() - field.trim_start().len();
+                offset += field.len() + 1;
+                parse_field(self, start, field.trim())
+            })
+            .collect()
+    }
+
+    /// Every integer in the line, like `get_all_numbers`, but reporting values
+    /// that do not fit in `T` instead of panicking.
+    pub fn numbers<T>(&self) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        RE_INTEGERS
+            .find_iter(self.text)
+            .map(|m| parse_field(self, m.start(), m.as_str()))
+            .collect()
+    }
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, text)| Line { index, text })
+}
+
+/// A run of lines from the input. Sections are separated by blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+    lines: Vec<Line<'a>>,
+}
+
+impl<'a> Section<'a> {
+    pub fn lines(&self) -> &[Line<'a>] {
+        &self.lines
+    }
+
+    pub fn lines_of<T>(&self) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        self.lines.iter().map(|line| line.parse()).collect()
+    }
+
+    /// Comma separated values from every line of the section, in order.
+    pub fn comma_separated<T>(&self) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        let mut result = Vec::new();
+        for line in self.lines.iter() {
+            result.extend(line.comma_separated()?);
+        }
+        Ok(result)
+    }
+
+    /// Parse one cell per character. Every row must have the same width.
+    pub fn parse_grid<T>(&self) -> Result<Grid<T>>
+    where
+        T: TryFrom<char>,
+    {
+        Grid::from_lines(&self.lines, |_, _, c| {
+            T::try_from(c).map_err(|_| format!("Unexpected character {:?}", c))
+        })
+    }
+
+    /// Parse a grid like `Grid::parse_with_markers`.
+    pub fn parse_grid_with_markers<T>(
+        &self,
+        markers: &str,
+        floor: char,
+    ) -> Result<(Grid<T>, Markers)>
+    where
+        T: TryFrom<char>,
+    {
+        Grid::from_lines_with_markers(&self.lines, markers, floor)
+    }
+}
+
+impl<'a> From<&'a str> for Section<'a> {
+    fn from(input: &'a str) -> Self {
+        Section {
+            lines: lines(input).collect(),
+        }
+    }
+}
+
+/// Split input into blank-line separated sections.
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut result = Vec::new();
+    let mut current = Vec::new();
+
+    for line in lines(input) {
+        if line.text.trim().is_empty() {
+            if !current.is_empty() {
+                result.push(Section {
+                    lines: std::mem::take(&mut current),
+                });
+            }
+        } else {
+            current.push(line);
+        }
+    }
+
+    if !current.is_empty() {
+        result.push(Section { lines: current });
+    }
+
+    result
+}
+
+/// Parse every line of the input as a `T`.
+pub fn lines_of<T>(input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    Section::from(input).lines_of()
+}
+
+pub fn comma_separated<T>(input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    Section::from(input).comma_separated()
+}
+
+pub fn parse_grid<T>(input: &str) -> Result<Grid<T>>
+where
+    T: TryFrom<char>,
 {
-    RE_INTEGERS
-        .find_iter(s)
-        .map(|s| s.as_str().parse::<T>().unwrap())
-        .collect()
+    Section::from(input).parse_grid()
+}
+
+/// Extracts fields from lines with a fixed layout, where each `{}` in the
+/// pattern stands for a field, e.g. `Pattern::new("Button A: X+{}, Y+{}")`.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    pattern: String,
+    re: Regex,
+}
+
+impl Pattern {
+    pub fn new(pattern: &str) -> Self {
+        let fields = pattern
+            .split("{}")
+            .map(regex::escape)
+            .collect::<Vec<_>>()
+            .join("(.*?)");
+        let re = Regex::new(&format!("^{}$", fields)).expect("Escaped pattern is a valid regex");
+
+        Self {
+            pattern: pattern.to_string(),
+            re,
+        }
+    }
+
+    pub fn extract<T>(&self, line: &Line) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        let captures = self.re.captures(line.text).ok_or_else(|| {
+            line.error(format!(
+                "Expected {:?}, found {:?}",
+                self.pattern, line.text
+            ))
+        })?;
+
+        captures
+            .iter()
+            .skip(1)
+            .flatten()
+            .map(|m| parse_field(line, m.start(), m.as_str()))
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -30,4 +297,129 @@ mod tests {
             vec![1, 2, 3, -5]
         );
     }
+
+    #[test]
+    fn test_sections() {
+        let sections = sections("a\nb\n\nc\n\n\nd\n");
+
+        assert_eq!(3, sections.len());
+        assert_eq!(3, sections[1].lines()[0].index);
+        assert_eq!(
+            Line {
+                index: 6,
+                text: "d"
+            },
+            sections[2].lines()[0]
+        );
+    }
+
+    #[test]
+    fn test_lines_of() {
+        assert_eq!(Ok(vec![3, -4, 5]), lines_of::<i32>("3\n-4\n 5 "));
+
+        let err = lines_of::<u8>("3\n  300").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 2,
+                column: Some(3),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_comma_separated() {
+        assert_eq!(
+            Ok(vec!["r".to_string(), "wr".to_string(), "b".to_string()]),
+            comma_separated::<String>("r, wr, b")
+        );
+
+        let err = comma_separated::<i32>("75,47,x1").unwrap_err();
+        assert_eq!(
+            "line 1, column 7: Invalid value \"x1\": invalid digit found in string",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_numbers_overflow() {
+        let line = Line {
+            index: 0,
+            text: "p=0,4 v=3,-300",
+        };
+
+        assert_eq!(Ok(vec![0, 4, 3, -300]), line.numbers::<i32>());
+        assert!(matches!(
+            line.numbers::<i8>(),
+            Err(AocError::Parse {
+                column: Some(11),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_parse_grid() {
+        let grid = parse_grid::<char>("ab\ncd").unwrap();
+        assert_eq!(&'d', grid.get(1, 1));
+
+        let err = parse_grid::<char>("ab\nc").unwrap_err();
+        assert_eq!("line 2: Expected 2 columns, found 1", err.to_string());
+    }
+
+    #[test]
+    fn test_parse_grid_with_markers() {
+        let sections = sections("#@\n..\n\n<>");
+        let (grid, markers) = sections[0]
+            .parse_grid_with_markers::<char>("@", '.')
+            .unwrap();
+        assert_eq!(&'.', grid.get(0, 1));
+        assert_eq!((0, 1), markers.single('@').unwrap());
+
+        let (_, markers) = sections[1]
+            .parse_grid_with_markers::<char>("@", '.')
+            .unwrap();
+        assert_eq!(
+            "invalid input: Expected one '@' marker, found 0",
+            markers.single('@').unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_pattern() {
+        let pattern = Pattern::new("Button A: X+{}, Y+{}");
+        let line = Line {
+            index: 4,
+            text: "Button A: X+94, Y+34",
+        };
+        assert_eq!(Ok(vec![94, 34]), pattern.extract::<i64>(&line));
+
+        let line = Line {
+            index: 4,
+            text: "Button B: X+22, Y+67",
+        };
+        assert!(matches!(
+            pattern.extract::<i64>(&line),
+            Err(AocError::Parse { line: 5, .. })
+        ));
+    }
+
+    #[test]
+    fn test_default_input_path() {
+        assert_eq!(
+            default_input_path("day_01"),
+            Path::new("inputs").join("day_01.txt")
+        );
+    }
+
+    #[test]
+    fn test_read_input_trims_trailing_whitespace() {
+        let path = std::env::temp_dir().join(format!("aoc_input_{}.txt", std::process::id()));
+        fs::write(&path, "  3   4\n4   3\n\n").unwrap();
+
+        let contents = read_input(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!("  3   4\n4   3", contents.unwrap());
+    }
 }
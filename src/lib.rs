@@ -1,25 +1,128 @@
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod circuit;
 pub mod collections;
+pub mod computer;
+pub mod context;
+pub mod days;
+pub mod error;
 pub mod input;
 pub mod sample;
+pub mod search;
 
-use std::{fs, path::PathBuf};
+use std::{
+    path::{Path, PathBuf},
+    process,
+};
 
+use answer::Answer;
+use answers::{Answers, Check};
 use clap::Parser;
-use tracing::info;
+use context::Context;
+use tracing::{info, warn};
 
-pub type Solution = fn(&str) -> String;
+pub type Solution = fn(&str, &Context) -> error::Result<Answer>;
+
+/// Return types accepted from a day's `part1`/`part2`: anything convertible
+/// into an `Answer`, or a `Result` of one.
+pub trait SolutionOutput {
+    fn into_result(self) -> error::Result<Answer>;
+}
+
+impl<T: Into<Answer>> SolutionOutput for T {
+    fn into_result(self) -> error::Result<Answer> {
+        Ok(self.into())
+    }
+}
+
+impl<T: Into<Answer>> SolutionOutput for error::Result<T> {
+    fn into_result(self) -> error::Result<Answer> {
+        self.map(Into::into)
+    }
+}
+
+/// Signatures accepted for a day's `part1`/`part2`: `fn(&str) -> T`, or
+/// `fn(&str, &Context) -> T` for parts that read puzzle parameters. The marker
+/// only distinguishes the two implementations.
+pub trait Part<Marker> {
+    type Output: SolutionOutput;
+
+    fn solve(&self, input: &str, ctx: &Context) -> Self::Output;
+}
+
+impl<F, T> Part<()> for F
+where
+    F: Fn(&str) -> T,
+    T: SolutionOutput,
+{
+    type Output = T;
+
+    fn solve(&self, input: &str, _ctx: &Context) -> T {
+        self(input)
+    }
+}
+
+impl<F, T> Part<Context> for F
+where
+    F: Fn(&str, &Context) -> T,
+    T: SolutionOutput,
+{
+    type Output = T;
+
+    fn solve(&self, input: &str, ctx: &Context) -> T {
+        self(input, ctx)
+    }
+}
+
+/// Adapt a day's `part1`/`part2` function into a `Solution`.
+#[macro_export]
+macro_rules! solution {
+    ($f:path) => {
+        |input: &str, ctx: &$crate::context::Context| {
+            $crate::SolutionOutput::into_result($crate::Part::solve(&$f, input, ctx))
+        }
+    };
+}
 
 #[derive(Debug, Parser)]
 struct Args {
     #[command(flatten)]
     verbosity: clap_verbosity_flag::Verbosity,
 
-    /// Override day from input path
+    /// Part to solve. Both parts are solved when omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Report input read and solve wall-clock time
     #[arg(short, long)]
-    part_two: bool,
+    time: bool,
+
+    /// Run the selected part N times and report timing statistics
+    #[arg(
+        short,
+        long,
+        value_name = "N",
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+        conflicts_with_all = ["check", "record"]
+    )]
+    bench: Option<usize>,
+
+    /// Compare results against the recorded answers and fail on a mismatch or
+    /// a missing answer
+    #[arg(short, long, conflicts_with = "record")]
+    check: bool,
 
-    /// Input path
-    input_file: PathBuf,
+    /// Record results as the expected answers
+    #[arg(short, long)]
+    record: bool,
+
+    /// Override a puzzle parameter, e.g. --param width=11
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = context::parse_param)]
+    params: Vec<(String, String)>,
+
+    /// Input path. Use - to read from stdin. Defaults to inputs/<binary name>.txt
+    input_file: Option<PathBuf>,
 }
 
 pub fn run(part1: Solution, part2: Option<Solution>) {
@@ -34,16 +137,126 @@ pub fn run(part1: Solution, part2: Option<Solution>) {
         .init();
 
     info!("Running {}", bin);
-    info!("Input file: {}", args.input_file.to_str().unwrap());
-    info!("Solving part {}", if args.part_two { "two" } else { "one" });
 
-    let contents = fs::read_to_string(&args.input_file).expect("Failed to read input file.");
+    let name = Path::new(&bin)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .expect("Failed to read binary name");
+
+    let input_file = args
+        .input_file
+        .unwrap_or_else(|| input::default_input_path(name));
+    info!("Input file: {}", input_file.to_str().unwrap());
+
+    let (contents, read_time) = match bench::time(|| input::read_input(&input_file)) {
+        (Ok(contents), elapsed) => (contents, elapsed),
+        (Err(e), _) => {
+            eprintln!("Failed to read {}: {}", input_file.to_str().unwrap(), e);
+            process::exit(1);
+        }
+    };
+
+    if args.time {
+        println!("Read: {:?}", read_time);
+    }
+
+    let mut parts = Vec::new();
+
+    if args.part != Some(2) {
+        parts.push((1, part1));
+    }
+
+    if args.part != Some(1) {
+        match part2 {
+            Some(p) => parts.push((2, p)),
+            None if args.part == Some(2) => {
+                eprintln!("Part two is not implemented.");
+                process::exit(1);
+            }
+            None => info!("Part two is not implemented. Skipping."),
+        }
+    }
 
-    let result = if args.part_two {
-        part2.expect("Part two not implemented.")(&contents)
+    let answers_file = answers::default_answers_path(name);
+    let mut answers = if args.check || args.record {
+        Answers::load(&answers_file).unwrap_or_else(|e| {
+            eprintln!("Failed to read {}: {}", answers_file.to_str().unwrap(), e);
+            process::exit(1);
+        })
     } else {
-        part1(&contents)
+        Answers::default()
     };
+    let mut failed = false;
+
+    let mut ctx = Context::new();
+    for (key, value) in args.params.iter() {
+        info!("Setting parameter {} = {}", key, value);
+        ctx.set(key, value);
+    }
+
+    for (part, solution) in parts {
+        info!("Solving part {}", part);
+
+        if let Some(iterations) = args.bench {
+            match bench::bench(solution, &contents, &ctx, iterations) {
+                Ok(stats) => println!("Part {}: {}", part, stats),
+                Err(e) => {
+                    eprintln!("Part {} failed: {}", part, e);
+                    failed = true;
+                }
+            }
+            continue;
+        }
+
+        let (result, solve_time) = match bench::time(|| solution(&contents, &ctx)) {
+            (Ok(result), elapsed) => (result, elapsed),
+            (Err(e), _) => {
+                eprintln!("Part {} failed: {}", part, e);
+                failed = true;
+                continue;
+            }
+        };
+
+        if args.check {
+            match answers.check(part, &result) {
+                Check::Correct => println!("Part {}: {} (correct)", part, result),
+                Check::Incorrect(expected) => {
+                    println!("Part {}: {} (expected {})", part, result, expected);
+                    failed = true;
+                }
+                Check::Unknown => {
+                    warn!("No recorded answer for part {}", part);
+                    println!("Part {}: {} (unknown)", part, result);
+                    failed = true;
+                }
+            }
+        } else {
+            println!("Part {}: {}", part, result);
+        }
+
+        if args.time {
+            println!("Solve: {:?}", solve_time);
+        }
+
+        if args.record {
+            answers.set(part, &result);
+        }
+    }
+
+    if args.record {
+        if let Err(e) = answers.save(&answers_file) {
+            eprintln!("Failed to write {}: {}", answers_file.to_str().unwrap(), e);
+            process::exit(1);
+        }
+        info!("Recorded answers to {}", answers_file.to_str().unwrap());
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
 
-    println!("{}", result);
+pub fn run_day(number: u32) {
+    let day = days::get(number).expect("Day not registered.");
+    run(day.part1, day.part2);
 }
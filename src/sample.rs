@@ -1,23 +1,70 @@
+/// Generate tests that run solutions against puzzle samples.
+///
+/// A single sample lists the functions to run and their expected answers:
+///
+/// ```ignore
+/// sample! {
+///     r"...",
+///     part1 = 11,
+///     part2 = 31
+/// }
+/// ```
+///
+/// Several samples may be given names, each producing its own test module.
+/// Arguments set parameters on the `Context` passed to the solution, and `as`
+/// renames the generated test when the same function is checked more than once.
+///
+/// ```ignore
+/// sample! {
+///     small: r"...", part1(width = 11, height = 7) = 12;
+///     large: r"...", part1 = 480, part2 = 875318608908
+/// }
+/// ```
 #[macro_export]
 macro_rules! sample {
-    ($input:literal, $($p1:ident = $v1:literal),*) => {
+    (@test [$name:ident $($_f:ident)?] $f:ident ($($arg:ident = $val:expr),*) = $v:expr) => {
+        paste::item! {
+            #[test_log::test]
+            fn [<test_ $name>]() {
+                let contents = SAMPLE.trim();
+                let ctx = $crate::context::Context::new()$(.with_param(stringify!($arg), $val))*;
+                let result = $crate::SolutionOutput::into_result($crate::Part::solve(&$f, contents, &ctx)).unwrap();
+                assert_eq!($crate::answer::Expected::into_answer($v), result);
+            }
+        }
+    };
+
+    (@tests $input:literal, $($f:ident $(($($arg:ident = $val:expr),*))? $(as $label:ident)? = $v:expr),* $(,)?) => {
+        const SAMPLE: &str = $input;
+
+        $(
+            $crate::sample!(@test [$($label)? $f] $f ($($($arg = $val),*)?) = $v);
+        )*
+    };
+
+    ($input:literal, $($tests:tt)*) => {
         #[cfg(test)]
         mod sample_tests {
 
             use super::*;
 
-            const SAMPLE: &str = $input;
-
-            paste::item! {
-                $(
-                    #[test_log::test]
-                    fn [<test_ $p1>]() {
-                        let contents = SAMPLE.trim();
-                        let result = $p1(contents);
-                        assert_eq!($v1, result);
-                    }
-                )*
-            }
+            $crate::sample!(@tests $input, $($tests)*);
+        }
+    };
+
+    ($($name:ident: $input:literal, $($f:ident $(($($arg:ident = $val:expr),*))? $(as $label:ident)? = $v:expr),+);+ $(;)?) => {
+        #[cfg(test)]
+        mod sample_tests {
+
+            use super::*;
+
+            $(
+                mod $name {
+                    use super::*;
+
+                    $crate::sample!(@tests $input, $($f $(($($arg = $val),*))? $(as $label)? = $v),+);
+                }
+            )+
         }
     };
 }
@@ -25,12 +72,27 @@ macro_rules! sample {
 #[cfg(test)]
 mod tests {
 
+    use crate::{context::Context, error::Result};
+
     fn identity(val: &str) -> String {
         val.to_string()
     }
 
+    fn repeat(val: &str, ctx: &Context) -> Result<String> {
+        Ok(val.repeat(ctx.param("count")?.unwrap_or(1)))
+    }
+
     sample! {
         "A",
         identity="A"
     }
+
+    mod named {
+        use super::*;
+
+        sample! {
+            first: "A", identity = "A", repeat(count = 2) as twice = "AA";
+            second: "B", repeat = "B", repeat(count = 3) as thrice = "BBB"
+        }
+    }
 }
@@ -0,0 +1,390 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::collections::grid::{get_direction_delta, Grid, CARDINAL_DIRECTIONS};
+
+/// Shortest distances from the start states of a search, along with every
+/// predecessor that lies on a shortest path so that all of them can be
+/// recovered.
+#[derive(Debug, Clone)]
+pub struct SearchResult<S> {
+    distances: HashMap<S, usize>,
+    predecessors: HashMap<S, Vec<S>>,
+    expanded: HashSet<S>,
+}
+
+impl<S> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+{
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            expanded: HashSet::new(),
+        }
+    }
+
+    /// Record reaching `next` from `prev` at `distance`. Returns true when
+    /// this is a new shortest distance.
+    ///
+    /// A zero-cost step to a state at the same distance is ignored once that
+    /// state has been expanded, so states joined by such steps never become
+    /// each other's predecessors.
+    fn relax(&mut self, prev: &S, next: S, distance: usize) -> bool {
+        match self.distances.get(&next) {
+            Some(d) if *d < distance => false,
+            Some(d)
+                if *d == distance
+                    && self.distances.get(prev) == Some(&distance)
+                    && self.expanded.contains(&next) =>
+            {
+                false
+            }
+            Some(d) if *d == distance => {
+                self.predecessors
+                    .entry(next)
+                    .or_default()
+                    .push(prev.clone());
+                false
+            }
+            _ => {
+                self.distances.insert(next.clone(), distance);
+                self.predecessors.insert(next, vec![prev.clone()]);
+                true
+            }
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, usize> {
+        &self.distances
+    }
+
+    /// States immediately before `state` on its shortest paths.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], |p| p.as_slice())
+    }
+
+    /// One shortest path from a start state to `goal`, inclusive.
+    pub fn path_to(&self, goal: &S) -> Option<Vec<S>> {
+        self.distances.get(goal)?;
+
+        let mut path = vec![goal.clone()];
+        while let Some(prev) = self.predecessors(path.last().unwrap()).first() {
+            path.push(prev.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every shortest path from a start state to `goal`. The number of paths
+    /// can grow exponentially, see `count_paths_to` and `states_on_paths_to`.
+    pub fn all_paths_to(&self, goal: &S) -> Vec<Vec<S>> {
+        if !self.distances.contains_key(goal) {
+            return Vec::new();
+        }
+
+        match self.predecessors(goal) {
+            [] => vec![vec![goal.clone()]],
+            prevs => prevs
+                .iter()
+                .flat_map(|p| self.all_paths_to(p))
+                .map(|mut path| {
+                    path.push(goal.clone());
+                    path
+                })
+                .collect(),
+        }
+    }
+
+    /// Number of distinct shortest paths to `goal`.
+    pub fn count_paths_to(&self, goal: &S) -> usize {
+        fn count<S: Clone + Eq + Hash>(
+            result: &SearchResult<S>,
+            state: &S,
+            cache: &mut HashMap<S, usize>,
+        ) -> usize {
+            if let Some(n) = cache.get(state) {
+                return *n;
+            }
+
+            let n = match result.predecessors(state) {
+                [] => 1,
+                prevs => prevs.iter().map(|p| count(result, p, cache)).sum(),
+            };
+            cache.insert(state.clone(), n);
+            n
+        }
+
+        match self.distances.contains_key(goal) {
+            true => count(self, goal, &mut HashMap::new()),
+            false => 0,
+        }
+    }
+
+    /// All states that appear on any shortest path to one of the goals.
+    pub fn states_on_paths_to<'a>(&self, goals: impl IntoIterator<Item = &'a S>) -> HashSet<S>
+    where
+        S: 'a,
+    {
+        let mut seen = HashSet::new();
+        let mut to_visit: Vec<_> = goals
+            .into_iter()
+            .filter(|g| self.distances.contains_key(g))
+            .cloned()
+            .collect();
+
+        while let Some(state) = to_visit.pop() {
+            if seen.insert(state.clone()) {
+                to_visit.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+
+        seen
+    }
+}
+
+/// Breadth first search where every step costs 1. Explores everything
+/// reachable from the start states.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut result = SearchResult::new();
+    let mut to_visit = VecDeque::new();
+
+    for start in starts {
+        result.distances.insert(start.clone(), 0);
+        to_visit.push_back(start);
+    }
+
+    while let Some(state) = to_visit.pop_front() {
+        let distance = result.distances[&state] + 1;
+
+        for next in successors(&state) {
+            if result.relax(&state, next.clone(), distance) {
+                to_visit.push_back(next);
+            }
+        }
+    }
+
+    result
+}
+
+/// Dijkstra's algorithm over successors paired with their step cost.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> SearchResult<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut result = SearchResult::new();
+
+    // States need not be `Ord`, so the heap orders indices into `queued`.
+    let mut queued = Vec::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        result.distances.insert(start.clone(), 0);
+        heap.push(Reverse((0, queued.len())));
+        queued.push(start);
+    }
+
+    while let Some(Reverse((distance, index))) = heap.pop() {
+        let state = queued[index].clone();
+        if result.distances[&state] < distance {
+            continue;
+        }
+        result.expanded.insert(state.clone());
+
+        for (next, cost) in successors(&state) {
+            if result.relax(&state, next.clone(), distance + cost) {
+                heap.push(Reverse((distance + cost, queued.len())));
+                queued.push(next);
+            }
+        }
+    }
+
+    result
+}
+
+/// A* search for the cheapest path from `start` to a state satisfying `goal`.
+/// `heuristic` must never overestimate the remaining cost. Returns the cost
+/// and the path, inclusive of both ends.
+pub fn astar<S, I>(
+    start: S,
+    mut goal: impl FnMut(&S) -> bool,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+) -> Option<(usize, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut result = SearchResult::new();
+    let mut queued = Vec::new();
+    let mut heap = BinaryHeap::new();
+
+    result.distances.insert(start.clone(), 0);
+    heap.push(Reverse((heuristic(&start), 0, 0)));
+    queued.push(start);
+
+    while let Some(Reverse((_, distance, index))) = heap.pop() {
+        let state = queued[index].clone();
+        if result.distances[&state] < distance {
+            continue;
+        }
+        result.expanded.insert(state.clone());
+
+        if goal(&state) {
+            return result.path_to(&state).map(|path| (distance, path));
+        }
+
+        for (next, cost) in successors(&state) {
+            let d = distance + cost;
+            if result.relax(&state, next.clone(), d) {
+                heap.push(Reverse((d + heuristic(&next), d, queued.len())));
+                queued.push(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Cardinal neighbours of a grid cell that are not walls.
+pub fn open_neighbors<'a, T>(
+    grid: &'a Grid<T>,
+    (row, col): (usize, usize),
+    wall: impl Fn(&T) -> bool + 'a,
+) -> impl Iterator<Item = (usize, usize)> + 'a {
+    CARDINAL_DIRECTIONS.iter().filter_map(move |dir| {
+        let (dr, dc) = get_direction_delta(*dir);
+        let pos = grid.position(row as isize + dr, col as isize + dc)?;
+        match wall(pos.value()) {
+            true => None,
+            false => Some(pos.into()),
+        }
+    })
+}
+
+/// Breadth first search over the open cells of a grid.
+pub fn grid_bfs<T>(
+    grid: &Grid<T>,
+    start: (usize, usize),
+    wall: impl Fn(&T) -> bool,
+) -> SearchResult<(usize, usize)> {
+    bfs([start], |p| open_neighbors(grid, *p, &wall))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("...\n.#.\n...").unwrap()
+    }
+
+    #[test]
+    fn test_grid_bfs() {
+        let grid = grid();
+        let result = grid_bfs(&grid, (0, 0), |c| *c == '#');
+
+        assert_eq!(Some(4), result.distance(&(2, 2)));
+        assert_eq!(None, result.distance(&(1, 1)));
+        assert_eq!(2, result.count_paths_to(&(2, 2)));
+        assert_eq!(2, result.all_paths_to(&(2, 2)).len());
+        assert_eq!(5, result.path_to(&(2, 2)).unwrap().len());
+        assert_eq!(8, result.states_on_paths_to([&(2, 2)]).len());
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // Going right is expensive, so the cheapest route to 3 is 0 -> 2 -> 3.
+        let edges = |n: &u32| match n {
+            0 => vec![(1, 1), (2, 5)],
+            1 => vec![(3, 10)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        };
+        let result = dijkstra([0], edges);
+
+        assert_eq!(Some(6), result.distance(&3));
+        assert_eq!(Some(vec![0, 2, 3]), result.path_to(&3));
+        assert_eq!(&[2], result.predecessors(&3));
+    }
+
+    #[test]
+    fn test_astar() {
+        let grid = grid();
+        let goal = (2, 2);
+        let result = astar(
+            (0, 0),
+            |p| *p == goal,
+            |p| open_neighbors(&grid, *p, |c| *c == '#').map(|n| (n, 1)),
+            |p: &(usize, usize)| p.0.abs_diff(goal.0) + p.1.abs_diff(goal.1),
+        );
+
+        let (cost, path) = result.unwrap();
+        assert_eq!(4, cost);
+        assert_eq!(Some(&goal), path.last());
+        assert!(astar(
+            0,
+            |n| *n == 5,
+            |n| [(n + 1, 1)].into_iter().take(usize::from(*n < 3)),
+            |_| 0
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_zero_cost_steps() {
+        // a and b are joined both ways at no cost, and b leads back to the
+        // start. b is expanded first, so only b -> a is kept.
+        let result = dijkstra(['s'], |c| match c {
+            's' => vec![('b', 1), ('a', 1)],
+            'a' => vec![('b', 0), ('c', 1)],
+            'b' => vec![('a', 0), ('s', 0)],
+            _ => vec![],
+        });
+
+        assert_eq!(Some(1), result.distance(&'b'));
+        assert_eq!(&['s', 'b'], result.predecessors(&'a'));
+        assert_eq!(&['s'], result.predecessors(&'b'));
+        assert_eq!(&['a'], result.predecessors(&'c'));
+        assert!(result.predecessors(&'s').is_empty());
+
+        assert_eq!(2, result.count_paths_to(&'c'));
+        assert_eq!(
+            vec![vec!['s', 'a', 'c'], vec!['s', 'b', 'a', 'c']],
+            result.all_paths_to(&'c')
+        );
+        assert_eq!(Some(vec!['s', 'a', 'c']), result.path_to(&'c'));
+    }
+
+    #[test]
+    fn test_zero_cost_improvement() {
+        let result = dijkstra(['s'], |c| match c {
+            's' => vec![('x', 5), ('y', 3)],
+            'y' => vec![('x', 0)],
+            _ => vec![],
+        });
+
+        assert_eq!(Some(3), result.distance(&'x'));
+        assert_eq!(Some(vec!['s', 'y', 'x']), result.path_to(&'x'));
+    }
+}